@@ -0,0 +1,73 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use crate::vec::Vector2;
+
+/// Alignment along a single axis, as used by `aligned_within` on rectangles.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Align {
+    /// Aligns the minimum edges.
+    #[default]
+    Start,
+    /// Aligns the centers.
+    Center,
+    /// Aligns the maximum edges.
+    End,
+}
+
+/// One of the nine standard anchor points of a 2-dimensional layout. Unlike
+/// [Direction4](crate::Direction4), these follow the Y-down convention used by most UI and image
+/// APIs, so `Top` refers to the minimum Y edge.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Anchor {
+    /// Aligns the minimum edges on both axes.
+    #[default]
+    TopLeft,
+    /// Aligns the centers on the X axis and the minimum edges on the Y axis.
+    Top,
+    /// Aligns the maximum edges on the X axis and the minimum edges on the Y axis.
+    TopRight,
+    /// Aligns the minimum edges on the X axis and the centers on the Y axis.
+    Left,
+    /// Aligns the centers on both axes.
+    Center,
+    /// Aligns the maximum edges on the X axis and the centers on the Y axis.
+    Right,
+    /// Aligns the minimum edges on the X axis and the maximum edges on the Y axis.
+    BottomLeft,
+    /// Aligns the centers on the X axis and the maximum edges on the Y axis.
+    Bottom,
+    /// Aligns the maximum edges on both axes.
+    BottomRight,
+}
+
+impl Anchor {
+    /// Returns the alignment along each axis which corresponds to this anchor.
+    pub const fn to_align(self) -> Vector2<Align> {
+        const ALIGN: [Align; 3] = [Align::Start, Align::Center, Align::End];
+        let i = self as usize;
+        Vector2 { x: ALIGN[i % 3], y: ALIGN[i / 3] }
+    }
+}
+
+impl From<Anchor> for Vector2<Align> {
+    fn from(anchor: Anchor) -> Vector2<Align> {
+        anchor.to_align()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_anchor() {
+    assert_eq!(Anchor::TopLeft.to_align(), Vector2::new(Align::Start, Align::Start));
+    assert_eq!(Anchor::Right.to_align(), Vector2::new(Align::End, Align::Center));
+    assert_eq!(Vector2::from(Anchor::Bottom), Vector2::new(Align::Center, Align::End));
+}
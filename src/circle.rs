@@ -0,0 +1,156 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::ops::{Add, Sub};
+
+#[cfg(feature = "std")]
+use crate::ops::ClosestPoint;
+use crate::ops::{BoundingRect, Contains, Dot};
+use crate::rect::{Rect2, Rect3};
+use crate::vec::{Vector2, Vector3};
+
+/// 2-dimensional circle structure defined by its center and radius.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Circle<T> {
+    pub center: Vector2<T>,
+    pub radius: T,
+}
+
+/// 3-dimensional sphere structure defined by its center and radius.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Sphere<T> {
+    pub center: Vector3<T>,
+    pub radius: T,
+}
+
+//--------------------------------------------------------------------------------------------------
+
+/// Implements common functions and traits for circles and spheres.
+macro_rules! impl_all {
+    { $(impl $shape:ident: $vec:ident($($field:ident),*), $rect:ident;)* } => { $(
+        impl<T> $shape<T> {
+            /// Constructs a new shape from its center and radius.
+            pub const fn new(center: $vec<T>, radius: T) -> $shape<T> {
+                $shape { center, radius }
+            }
+        }
+
+        impl<T> BoundingRect for $shape<T>
+        where T: Add<Output = T> + Clone + Sub<Output = T>
+        {
+            type Output = $rect<T>;
+
+            fn bounding_rect(&self) -> $rect<T> {
+                let r = $vec { $($field: self.radius.clone()),* };
+                $rect(self.center.clone() - r.clone(), self.center.clone() + r)
+            }
+        }
+    )* };
+}
+
+/// Implements [Contains] for circles and spheres with integer components. Squared distances are
+/// computed in `u128`, so the test is exact and never overflows.
+macro_rules! impl_contains_int {
+    { $($t:ident),* } => { $(
+        impl_contains_int!(@impl $t: Circle: Vector2(x, y), Sphere: Vector3(x, y, z));
+    )* };
+    { @impl $t:ident: $($shape:ident: $vec:ident($($field:ident),*)),* } => { $(
+        impl Contains<$vec<$t>> for $shape<$t> {
+            fn contains(&self, rhs: &$vec<$t>) -> bool {
+                let r = (self.radius as i128).unsigned_abs();
+                let mut sum = 0u128;
+                $(let d = (rhs.$field as i128 - self.center.$field as i128).unsigned_abs();
+                // A sum which overflows is certainly greater than the squared radius.
+                sum = match sum.checked_add(d * d) {
+                    Some(sum) => sum,
+                    None => return false,
+                };)*
+                sum <= r * r
+            }
+        }
+    )* };
+}
+
+/// Implements [Contains] for circles and spheres with floating point components.
+macro_rules! impl_contains_float {
+    { $($t:ident),* } => { $(
+        impl_contains_float!(@impl $t: Circle: Vector2, Sphere: Vector3);
+    )* };
+    { @impl $t:ident: $($shape:ident: $vec:ident),* } => { $(
+        impl Contains<$vec<$t>> for $shape<$t> {
+            fn contains(&self, rhs: &$vec<$t>) -> bool {
+                let d = *rhs - self.center;
+                d.dot(d) <= self.radius * self.radius
+            }
+        }
+    )* };
+}
+
+/// Implements functions for circles and spheres with floating point components.
+#[cfg(feature = "std")]
+macro_rules! impl_float {
+    { $(impl $shape:ident<$t:ident>: $vec:ident;)* } => { $(
+        impl ClosestPoint<$vec<$t>> for $shape<$t> {
+            fn closest_point(&self, point: &$vec<$t>) -> $vec<$t> {
+                let d = *point - self.center;
+                let len_squared = d.dot(d);
+                if len_squared <= self.radius * self.radius {
+                    return *point;
+                }
+                self.center + d * (self.radius / len_squared.sqrt())
+            }
+        }
+    )* };
+}
+
+impl_all! {
+    impl Circle: Vector2(x, y), Rect2;
+    impl Sphere: Vector3(x, y, z), Rect3;
+}
+
+impl_contains_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_contains_float!(f32, f64);
+
+#[cfg(feature = "std")]
+impl_float! {
+    impl Circle<f32>: Vector2;
+    impl Circle<f64>: Vector2;
+    impl Sphere<f32>: Vector3;
+    impl Sphere<f64>: Vector3;
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_circle() {
+    let c = Circle::new(Vector2::new(1, 1), 5);
+    assert!(c.contains(&Vector2::new(4, 5)));
+    assert!(!c.contains(&Vector2::new(5, 5)));
+    assert_eq!(c.bounding_rect(), Rect2::new(-4, -4, 6, 6));
+    assert!(Sphere::new(Vector3::new(0.0, 0.0, 0.0), 1.0).contains(&Vector3::new(0.5, 0.5, 0.5)));
+
+    // Unsigned and extreme components must neither underflow nor overflow.
+    assert!(Circle::new(Vector2::new(5u32, 5), 2).contains(&Vector2::new(4, 5)));
+    assert!(!Circle::new(Vector2::new(5u32, 5), 2).contains(&Vector2::new(2, 5)));
+    let c = Circle::new(Vector2::new(i64::MIN, 0), i64::MAX);
+    assert!(c.contains(&Vector2::new(-1, 0)));
+    assert!(!c.contains(&Vector2::new(0, 0)));
+    let s = Sphere::new(Vector3::new(u64::MIN, 0, 0), u64::MAX);
+    assert!(!s.contains(&Vector3::new(u64::MAX, u64::MAX, u64::MAX)));
+    assert!(s.contains(&Vector3::new(u64::MAX, 0, 0)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_circle_closest_point() {
+    let s = Sphere::new(Vector3::new(1.0f64, 0.0, 0.0), 2.0);
+    assert_eq!(s.closest_point(&Vector3::new(1.0, 0.0, 5.0)), Vector3::new(1.0, 0.0, 2.0));
+    assert_eq!(s.closest_point(&Vector3::new(2.0, 0.0, 0.0)), Vector3::new(2.0, 0.0, 0.0));
+}
@@ -0,0 +1,153 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+/// Specifies how values are rounded to integers when converting from floating point types.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RoundingMode {
+    /// Rounds toward positive infinity.
+    Ceil,
+    /// Rounds toward negative infinity.
+    Floor,
+    /// Rounds to the nearest integer, rounding half-way cases away from zero.
+    Round,
+    /// Rounds toward zero.
+    Trunc,
+}
+
+/// Numeric conversion which clamps values that are out of range to the bounds of the target type.
+/// Conversions from floating point types to integers map `NaN` to zero and clamp infinities.
+/// Conversions between floating point types preserve both `NaN` and infinities.
+pub trait SaturatingFrom<T> {
+    fn saturating_from(value: T) -> Self;
+}
+
+/// Numeric conversion with the semantics of an `as` cast. Integers which are out of range wrap
+/// around at the bounds of the target type.
+pub trait WrappingFrom<T> {
+    fn wrapping_from(value: T) -> Self;
+}
+
+//--------------------------------------------------------------------------------------------------
+
+/// Implements conversions from integer types to integer types.
+macro_rules! impl_int_to_int {
+    { $($src:ident),* } => { $(
+        impl_int_to_int!(@impl $src: i8, i16, i32, i64, i128, isize,
+                         u8, u16, u32, u64, u128, usize);
+    )* };
+    { @impl $src:ident: $($dst:ident),* } => { $(
+        impl SaturatingFrom<$src> for $dst {
+            fn saturating_from(value: $src) -> $dst {
+                match <$dst>::try_from(value) {
+                    Ok(n) => n,
+                    Err(_) if value > 0 => <$dst>::MAX,
+                    Err(_) => <$dst>::MIN,
+                }
+            }
+        }
+
+        impl WrappingFrom<$src> for $dst {
+            fn wrapping_from(value: $src) -> $dst {
+                value as $dst
+            }
+        }
+    )* };
+}
+
+/// Implements conversions between floating point types, which only saturate with `as` if the
+/// source is infinite.
+macro_rules! impl_float_to_float {
+    { $($src:ident),* => $dst:tt } => { $(
+        impl_float_to_float!(@impl $src => $dst);
+    )* };
+    { @impl $src:ident => ($($dst:ident),*) } => { $(
+        impl SaturatingFrom<$src> for $dst {
+            fn saturating_from(value: $src) -> $dst {
+                if value.is_nan() || value.is_infinite() {
+                    value as $dst
+                } else {
+                    value.clamp(<$dst>::MIN as $src, <$dst>::MAX as $src) as $dst
+                }
+            }
+        }
+
+        impl WrappingFrom<$src> for $dst {
+            fn wrapping_from(value: $src) -> $dst {
+                value as $dst
+            }
+        }
+    )* };
+}
+
+/// Implements conversions which are already saturating when performed with `as`.
+macro_rules! impl_as {
+    { $($src:ident),* => $dst:tt } => { $(
+        impl_as!(@impl $src => $dst);
+    )* };
+    { @impl $src:ident => ($($dst:ident),*) } => { $(
+        impl SaturatingFrom<$src> for $dst {
+            fn saturating_from(value: $src) -> $dst {
+                value as $dst
+            }
+        }
+
+        impl WrappingFrom<$src> for $dst {
+            fn wrapping_from(value: $src) -> $dst {
+                value as $dst
+            }
+        }
+    )* };
+}
+
+impl_int_to_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl_float_to_float!(f32, f64 => (f32, f64));
+
+impl_as! {
+    f32, f64 => (i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize)
+}
+
+impl_as! {
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize => (f32, f64)
+}
+
+impl_as!(u128 => (f64));
+
+// `u128::MAX` rounds up past `f32::MAX`, so `as` yields infinity.
+impl SaturatingFrom<u128> for f32 {
+    fn saturating_from(value: u128) -> f32 {
+        let n = value as f32;
+        if n.is_infinite() { f32::MAX } else { n }
+    }
+}
+
+impl WrappingFrom<u128> for f32 {
+    fn wrapping_from(value: u128) -> f32 {
+        value as f32
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_saturating_from() {
+    assert_eq!(u8::saturating_from(300i32), 255);
+    assert_eq!(u8::saturating_from(-3i32), 0);
+    assert_eq!(i16::saturating_from(u64::MAX), i16::MAX);
+    assert_eq!(i8::saturating_from(-1000.5f32), i8::MIN);
+    assert_eq!(u8::wrapping_from(300i32), 44);
+    assert_eq!(f32::saturating_from(1e300f64), f32::MAX);
+    assert_eq!(f32::saturating_from(-1e300f64), f32::MIN);
+    assert!(f32::saturating_from(f64::NAN).is_nan());
+    assert!(f64::saturating_from(f32::NAN).is_nan());
+    assert_eq!(i32::saturating_from(f64::NAN), 0);
+    assert_eq!(f32::saturating_from(f64::INFINITY), f32::INFINITY);
+    assert_eq!(f32::saturating_from(u128::MAX), f32::MAX);
+    assert_eq!(f32::wrapping_from(u128::MAX), f32::INFINITY);
+}
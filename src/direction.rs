@@ -0,0 +1,180 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use crate::vec::{Vector2, Vector3};
+
+/// One of the four cardinal directions on a 2-dimensional grid. Variants are listed in clockwise
+/// order and follow the Y-up convention of [Vector2::UP](Vector2::UP).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// One of the four cardinal or four diagonal directions on a 2-dimensional grid. Variants are
+/// listed in clockwise order and follow the Y-up convention of [Vector2::UP](Vector2::UP).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+/// One of the six directions along the axes of a 3-dimensional grid. Variants follow the Y-up,
+/// right-handed convention of [Vector3::UP](Vector3::UP), in which forward is `-Z`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Direction6 {
+    Right,
+    Left,
+    Up,
+    Down,
+    Back,
+    Forward,
+}
+
+impl Direction4 {
+    /// All directions in clockwise order, starting with `Up`.
+    pub const ALL: [Direction4; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Returns the direction pointing the opposite way.
+    pub const fn opposite(self) -> Direction4 {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Returns the direction a quarter turn counterclockwise from `self`.
+    pub const fn rotate_ccw(self) -> Direction4 {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    /// Returns the direction a quarter turn clockwise from `self`.
+    pub const fn rotate_cw(self) -> Direction4 {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Returns the unit vector pointing in this direction.
+    pub fn to_vector<T: From<i8>>(self) -> Vector2<T> {
+        let (x, y) = match self {
+            Self::Up => (0, 1),
+            Self::Right => (1, 0),
+            Self::Down => (0, -1),
+            Self::Left => (-1, 0),
+        };
+        Vector2::new(T::from(x), T::from(y))
+    }
+}
+
+impl Direction8 {
+    /// All directions in clockwise order, starting with `Up`.
+    pub const ALL: [Direction8; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// Returns `true` if the direction is diagonal.
+    pub const fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// Returns the direction pointing the opposite way.
+    pub const fn opposite(self) -> Direction8 {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Returns the direction an eighth of a turn counterclockwise from `self`.
+    pub const fn rotate_ccw(self) -> Direction8 {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Returns the direction an eighth of a turn clockwise from `self`.
+    pub const fn rotate_cw(self) -> Direction8 {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Returns the vector pointing in this direction. Diagonal directions are not normalized, so
+    /// each of their components is `1` or `-1`.
+    pub fn to_vector<T: From<i8>>(self) -> Vector2<T> {
+        let (x, y) = match self {
+            Self::Up => (0, 1),
+            Self::UpRight => (1, 1),
+            Self::Right => (1, 0),
+            Self::DownRight => (1, -1),
+            Self::Down => (0, -1),
+            Self::DownLeft => (-1, -1),
+            Self::Left => (-1, 0),
+            Self::UpLeft => (-1, 1),
+        };
+        Vector2::new(T::from(x), T::from(y))
+    }
+}
+
+impl Direction6 {
+    /// All directions, ordered by axis with the positive direction first.
+    pub const ALL: [Direction6; 6] = [
+        Self::Right,
+        Self::Left,
+        Self::Up,
+        Self::Down,
+        Self::Back,
+        Self::Forward,
+    ];
+
+    /// Returns the direction pointing the opposite way.
+    pub const fn opposite(self) -> Direction6 {
+        Self::ALL[self as usize ^ 1]
+    }
+
+    /// Returns the unit vector pointing in this direction.
+    pub fn to_vector<T: From<i8>>(self) -> Vector3<T> {
+        let (x, y, z) = match self {
+            Self::Right => (1, 0, 0),
+            Self::Left => (-1, 0, 0),
+            Self::Up => (0, 1, 0),
+            Self::Down => (0, -1, 0),
+            Self::Back => (0, 0, 1),
+            Self::Forward => (0, 0, -1),
+        };
+        Vector3::new(T::from(x), T::from(y), T::from(z))
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(dir: Direction4) -> Direction8 {
+        Direction8::ALL[dir as usize * 2]
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_directions() {
+    assert_eq!(Direction4::Left.rotate_cw(), Direction4::Up);
+    assert_eq!(Direction8::Up.rotate_ccw(), Direction8::UpLeft);
+    assert_eq!(Direction8::from(Direction4::Down).opposite(), Direction8::Up);
+    assert_eq!(Direction6::Forward.opposite(), Direction6::Back);
+    assert_eq!(Direction8::DownLeft.to_vector::<f32>(), Vector2::new(-1.0, -1.0));
+    assert_eq!(Direction6::Forward.to_vector(), Vector3::<i32>::FORWARD);
+    for dir in Direction4::ALL {
+        assert_eq!(dir.opposite().to_vector::<i32>(), -dir.to_vector::<i32>());
+    }
+}
@@ -9,6 +9,14 @@
 //! Vectors and related mathematic types.
 
 #![cfg_attr(not(feature = "std"), no_std)]
+// Conversions out of this crate's types are written as `Into` impls on those types.
+#![allow(clippy::from_over_into)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "az")]
+extern crate az;
 
 #[cfg(feature = "cgmath")]
 extern crate cgmath;
@@ -16,6 +24,12 @@ extern crate cgmath;
 #[cfg(feature = "ext-ops")]
 extern crate ext_ops;
 
+#[cfg(feature = "fixed")]
+extern crate fixed;
+
+#[cfg(feature = "half")]
+extern crate half;
+
 #[cfg(feature = "num-complex")]
 extern crate num_complex;
 
@@ -26,10 +40,71 @@ extern crate num_traits;
 #[macro_use]
 extern crate serde;
 
+mod align;
+mod axis;
+mod circle;
+mod convert;
+mod direction;
 mod ops;
+mod order;
+#[cfg(feature = "alloc")]
+mod pack;
+#[cfg(feature = "alloc")]
+mod polygon;
+mod quad;
+#[cfg(feature = "alloc")]
+mod raster;
+mod ray;
 mod rect;
+mod rotation;
+mod segment;
+mod triangle;
 mod vec;
 
-pub use ops::{Cross, Dot, cross, dot};
-pub use rect::{Rect2, Rect3};
+pub use align::{Align, Anchor};
+pub use axis::{Axis2, Axis3, Axis4};
+pub use circle::{Circle, Sphere};
+pub use convert::{RoundingMode, SaturatingFrom, WrappingFrom};
+pub use direction::{Direction4, Direction6, Direction8};
+pub use ops::{
+    BoundingRect,
+    ClosestPoint,
+    ComponentWise,
+    Contains,
+    Cross,
+    Distance,
+    Dot,
+    InnerSpace,
+    L1,
+    L2,
+    LInf,
+    Lerp,
+    MetricSpace,
+    Norm,
+    Orient2d,
+    Select,
+    VectorN,
+    VectorSpace,
+    cross,
+    dot,
+    lerp,
+    orient2d,
+    select,
+    triple,
+};
+pub use order::{Lexicographic, TotalOrd};
+#[cfg(feature = "alloc")]
+pub use pack::SkylinePacker;
+#[cfg(feature = "alloc")]
+pub use polygon::{Polygon2, convex_hull};
+pub use quad::Quad2;
+#[cfg(feature = "alloc")]
+pub use raster::scanline_spans;
+pub use ray::Ray3;
+pub use rect::{ParseRectError, Rect2, Rect3, RectKind};
+#[cfg(feature = "serde")]
+pub use rect::{RectMinMax, RectOriginSize};
+pub use rotation::Rotation90;
+pub use segment::{Segment2, Segment3};
+pub use triangle::{Triangle2, Triangle3};
 pub use vec::{Vector2, Vector3, Vector4, vec2, vec3, vec4};
@@ -6,18 +6,172 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+use core::cmp::Ordering;
+use core::ops::{Add, Div, Index, IndexMut, Mul, Sub};
+
+/// Computes the smallest axis-aligned rectangle containing a shape.
+pub trait BoundingRect {
+    type Output;
+    fn bounding_rect(&self) -> Self::Output;
+}
+
+/// Finds the point within a shape which is closest to another point. Shapes are treated as solid,
+/// so a point which the shape contains is returned unchanged.
+pub trait ClosestPoint<P> {
+    fn closest_point(&self, point: &P) -> P;
+}
+
+/// Structure made of a fixed number of scalar components of the same type, allowing generic code
+/// to operate on each component regardless of the dimension.
+pub trait ComponentWise: Sized {
+    type Scalar;
+
+    /// The same structure with components of type `U`.
+    type Output<U>: ComponentWise<Scalar = U>;
+
+    /// The number of components.
+    const DIM: usize;
+
+    /// Folds every component into an accumulator, in order.
+    fn fold<A, F>(self, init: A, f: F) -> A
+    where F: FnMut(A, Self::Scalar) -> A;
+
+    /// Applies `f` to each component.
+    fn map<U, F>(self, f: F) -> Self::Output<U>
+    where F: FnMut(Self::Scalar) -> U;
+
+    /// Applies `f` to each pair of corresponding components of `self` and `rhs`.
+    fn zip_with<U, V, F>(self, rhs: Self::Output<U>, f: F) -> Self::Output<V>
+    where F: FnMut(Self::Scalar, U) -> V;
+}
+
+/// Tests whether a shape contains a point or another shape. Points on the boundary of a shape are
+/// considered to be contained by it, except that rectangles cover half-open ranges like grid
+/// cells, so points on their maximum edges are not contained.
+pub trait Contains<Rhs: ?Sized> {
+    fn contains(&self, rhs: &Rhs) -> bool;
+}
+
 /// Computes a cross product.
 pub trait Cross<Rhs = Self> {
     type Output;
     fn cross(self, rhs: Rhs) -> Self::Output;
 }
 
+/// Computes the Euclidean distance between the closest points of two geometric entities, which is
+/// zero if they touch or overlap. Distances between two vectors are provided by [MetricSpace]
+/// instead, whose methods have distinct names so that both traits can be used together.
+pub trait Distance<Rhs = Self> {
+    type Output;
+
+    /// Returns the distance between `self` and `rhs`.
+    fn distance_to(&self, rhs: &Rhs) -> Self::Output;
+
+    /// Returns the squared distance between `self` and `rhs`, which is often cheaper to compute
+    /// than the distance itself.
+    fn distance_squared_to(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Computes a dot product.
 pub trait Dot<Rhs = Self> {
     type Output;
     fn dot(self, rhs: Rhs) -> Self::Output;
 }
 
+/// Vector space with an inner product, which defines the magnitude of its vectors.
+pub trait InnerSpace: VectorSpace + Dot<Output = <Self as VectorSpace>::Scalar> {
+    /// Returns the squared magnitude of the vector, which is its inner product with itself.
+    fn magnitude_squared(self) -> Self::Scalar {
+        self.dot(self)
+    }
+}
+
+/// Linearly interpolates between two values by a factor of type `F`, where a factor of zero
+/// yields `self` and a factor of one yields `rhs`.
+pub trait Lerp<F = Self> {
+    fn lerp(self, rhs: Self, t: F) -> Self;
+}
+
+/// Space with a notion of distance between its elements.
+pub trait MetricSpace: Sized {
+    type Metric;
+
+    /// Returns the distance between `self` and `other`.
+    fn distance(self, other: Self) -> Self::Metric;
+
+    /// Returns the squared distance between `self` and `other`, which is often cheaper to compute
+    /// than the distance itself.
+    fn distance_squared(self, other: Self) -> Self::Metric;
+}
+
+/// Computes the norm (length) of a vector as defined by the metric `M`, which is typically one of
+/// [L1], [L2] or [LInf].
+pub trait Norm<M> {
+    type Output;
+    fn norm(self, metric: M) -> Self::Output;
+}
+
+/// Computes the orientation of three points in the plane, which is the sign of
+/// `(b - a) × (c - a)`. `Greater` means that `a`, `b` and `c` wind counter-clockwise (assuming the
+/// y axis points up), `Less` means that they wind clockwise, and `Equal` means that they are
+/// collinear. Returns `None` if the orientation cannot be determined, as with NaN.
+pub trait Orient2d {
+    fn orient2d(&self, b: &Self, c: &Self) -> Option<Ordering>;
+}
+
+/// Chooses between the components of two values according to a mask.
+pub trait Select<T> {
+    fn select(self, if_true: T, if_false: T) -> T;
+}
+
+/// Vector space over a scalar type, supporting addition, subtraction and scaling. Vectors and
+/// scalars must be `Copy` so that generic code can use them by value more than once, so vectors of
+/// arbitrary-precision scalars do not implement this trait.
+pub trait VectorSpace:
+    Copy
+    + Add<Output = Self>
+    + Div<<Self as VectorSpace>::Scalar, Output = Self>
+    + Mul<<Self as VectorSpace>::Scalar, Output = Self>
+    + Sub<Output = Self>
+{
+    type Scalar: Copy;
+
+    /// Returns the additive identity.
+    fn zero() -> Self;
+}
+
+/// Vector of any dimension whose components can be accessed by index, allowing algorithms to be
+/// written once for every dimension.
+pub trait VectorN:
+    ComponentWise
+    + Index<usize, Output = <Self as ComponentWise>::Scalar>
+    + IndexMut<usize>
+{
+    /// Array of [DIM](ComponentWise::DIM) components.
+    type Array;
+
+    /// Constructs a vector from an array of its components.
+    fn from_array(array: Self::Array) -> Self;
+
+    /// Converts the vector to an array of its components.
+    fn to_array(self) -> Self::Array;
+}
+
+/// Manhattan (taxicab) metric, under which the norm of a vector is the sum of the absolute values
+/// of its components.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct L1;
+
+/// Euclidean metric, under which the norm of a vector is the square root of the sum of the squares
+/// of its components. Use [InnerSpace::magnitude_squared] for the squared norm.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct L2;
+
+/// Chebyshev (chessboard) metric, under which the norm of a vector is the greatest absolute value
+/// of its components.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct LInf;
+
 /// Computes a cross product.
 pub fn cross<Lhs, Rhs>(lhs: Lhs, rhs: Rhs) -> <Lhs as Cross<Rhs>>::Output
 where Lhs: Cross<Rhs>
@@ -31,3 +185,49 @@ where Lhs: Dot<Rhs>
 {
     Dot::dot(lhs, rhs)
 }
+
+/// Linearly interpolates between `a` and `b` by a factor of `t`.
+pub fn lerp<T, F>(a: T, b: T, t: F) -> T
+where T: Lerp<F>
+{
+    a.lerp(b, t)
+}
+
+/// Computes the orientation of three points. See [Orient2d].
+pub fn orient2d<T>(a: &T, b: &T, c: &T) -> Option<Ordering>
+where T: Orient2d
+{
+    a.orient2d(b, c)
+}
+
+/// Chooses between the components of `if_true` and `if_false` according to `mask`.
+pub fn select<M, T>(mask: M, if_true: T, if_false: T) -> T
+where M: Select<T>
+{
+    mask.select(if_true, if_false)
+}
+
+/// Computes the scalar triple product `a · (b × c)`, which is the signed volume of the
+/// parallelepiped defined by three vectors.
+pub fn triple<A, B, C>(a: A, b: B, c: C) -> <A as Dot<<B as Cross<C>>::Output>>::Output
+where A: Dot<<B as Cross<C>>::Output>,
+      B: Cross<C>
+{
+    a.dot(b.cross(c))
+}
+
+//--------------------------------------------------------------------------------------------------
+
+/// Implements [Lerp] for primitive floating point types.
+macro_rules! impl_lerp {
+    { $($t:ident),* } => { $(
+        impl Lerp for $t {
+            /// Computes `self * (1 - t) + rhs * t`, which is exact when `t` is zero or one.
+            fn lerp(self, rhs: $t, t: $t) -> $t {
+                self * (1.0 - t) + rhs * t
+            }
+        }
+    )* };
+}
+
+impl_lerp!(f32, f64);
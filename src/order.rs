@@ -0,0 +1,112 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+use crate::vec::{Vector2, Vector3, Vector4};
+#[cfg(test)]
+use crate::vec::{vec2, vec3};
+
+/// Wrapper which orders vectors lexicographically, comparing `x` first, then `y`, and so on.
+/// Vectors themselves are deliberately not ordered, since no single ordering is natural for them,
+/// but this wrapper allows them to be sorted or used as keys in a `BTreeMap`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Lexicographic<V>(pub V);
+
+/// Wrapper which totally orders floating point vectors using
+/// [total_cmp](crate::Vector2::total_cmp), so that they can be deduplicated, sorted or used as
+/// keys in a `BTreeMap` or `HashMap`. Equality also follows the total ordering, so `NaN` is equal
+/// to itself, and `0.0` and `-0.0` are distinct.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TotalOrd<V>(pub V);
+
+//--------------------------------------------------------------------------------------------------
+
+/// Implements ordering traits for wrapped vector types.
+macro_rules! impl_lexicographic {
+    { $(impl $vec:ident($($field:ident),*);)* } => { $(
+        impl<T: Ord> Ord for Lexicographic<$vec<T>> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                ($(&self.0.$field),*).cmp(&($(&other.0.$field),*))
+            }
+        }
+
+        impl<T: PartialOrd> PartialOrd for Lexicographic<$vec<T>> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                ($(&self.0.$field),*).partial_cmp(&($(&other.0.$field),*))
+            }
+        }
+    )* };
+}
+
+/// Implements ordering traits for totally ordered floating point vector types.
+macro_rules! impl_total_ord {
+    { $($t:ident),* } => { $(
+        impl_total_ord!(@impl $t: Vector2(x, y), Vector3(x, y, z), Vector4(x, y, z, w));
+    )* };
+    { @impl $t:ident: $($vec:ident($($field:ident),*)),* } => { $(
+        impl Eq for TotalOrd<$vec<$t>> {}
+
+        impl Hash for TotalOrd<$vec<$t>> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                $(self.0.$field.to_bits().hash(state);)*
+            }
+        }
+
+        impl Ord for TotalOrd<$vec<$t>> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl PartialEq for TotalOrd<$vec<$t>> {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Ordering::Equal
+            }
+        }
+
+        impl PartialOrd for TotalOrd<$vec<$t>> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+    )* };
+}
+
+impl_lexicographic! {
+    impl Vector2(x, y);
+    impl Vector3(x, y, z);
+    impl Vector4(x, y, z, w);
+}
+
+impl_total_ord!(f32, f64);
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_lexicographic() {
+    let mut points = [Vector2::new(1, 5), Vector2::new(0, 9), Vector2::new(1, -2)];
+    points.sort_by_key(|&v| Lexicographic(v));
+    assert_eq!(points, [Vector2::new(0, 9), Vector2::new(1, -2), Vector2::new(1, 5)]);
+    assert!(Lexicographic(vec3(0.0, 1.0, 2.0)) < Lexicographic(vec3(0.0, 1.5, 0.0)));
+    assert_eq!(Lexicographic(vec2(f32::NAN, 0.0)).partial_cmp(&Lexicographic(vec2(0.0, 0.0))),
+               None);
+}
+
+#[test]
+fn test_total_ord() {
+    let mut points = [vec2(1.0, f32::NAN), vec2(-0.0, 2.0), vec2(1.0, f32::NAN), vec2(0.0, 1.0)];
+    points.sort_by_key(|&v| TotalOrd(v));
+    assert_eq!(points.map(TotalOrd),
+               [vec2(-0.0, 2.0), vec2(0.0, 1.0), vec2(1.0, f32::NAN), vec2(1.0, f32::NAN)]
+                   .map(TotalOrd));
+    assert_ne!(TotalOrd(vec2(0.0f64, 0.0)), TotalOrd(vec2(-0.0, 0.0)));
+}
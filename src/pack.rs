@@ -0,0 +1,126 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Add;
+
+use crate::rect::Rect2;
+use crate::vec::Vector2;
+
+/// Packs rectangles into a fixed container, as for texture atlases and sprite sheets, using the
+/// skyline bottom-left heuristic. Each rectangle is placed as close to `bounds.0.y` as possible,
+/// then as close to `bounds.0.x` as possible. Rectangles are never rotated. This is only
+/// available with the `alloc` feature.
+#[derive(Clone, Debug)]
+pub struct SkylinePacker<T> {
+    bounds: Rect2<T>,
+    // Start point of each skyline segment, sorted by X. Each segment extends to the start of the
+    // next, or to `bounds.1.x` for the last segment.
+    skyline: Vec<Vector2<T>>,
+}
+
+impl<T> SkylinePacker<T>
+where T: Add<Output = T> + Clone + Default + PartialOrd
+{
+    /// Constructs an empty packer for the area covered by `bounds`, which is assumed to be
+    /// positive.
+    pub fn new(bounds: Rect2<T>) -> SkylinePacker<T> {
+        SkylinePacker { skyline: vec![bounds.0.clone()], bounds }
+    }
+
+    /// Returns the area into which rectangles are packed.
+    pub fn bounds(&self) -> &Rect2<T> {
+        &self.bounds
+    }
+
+    /// Reserves space for a rectangle of the given size and returns where it was placed. Returns
+    /// `None` if the size is not positive or if there is no room for it.
+    pub fn insert(&mut self, size: Vector2<T>) -> Option<Rect2<T>> {
+        if !(size.x > T::default() && size.y > T::default()) {
+            return None;
+        }
+
+        // Find the lowest position, then the leftmost, at which the rectangle fits.
+        let mut best: Option<(usize, Vector2<T>)> = None;
+        for i in 0..self.skyline.len() {
+            let x = self.skyline[i].x.clone();
+            let end = x.clone() + size.x.clone();
+            if end > self.bounds.1.x {
+                break;
+            }
+            let y = self.skyline[i..].iter()
+                .take_while(|p| p.x < end)
+                .map(|p| p.y.clone())
+                .fold(self.skyline[i].y.clone(), |a, b| if b > a { b } else { a });
+            if y.clone() + size.y.clone() > self.bounds.1.y {
+                continue;
+            }
+            if best.as_ref().is_none_or(|(_, p)| y < p.y) {
+                best = Some((i, Vector2::new(x, y)));
+            }
+        }
+
+        let (i, min) = best?;
+        let max = min.clone() + size;
+        let covered = self.skyline[i..].iter().take_while(|p| p.x < max.x).count();
+        let last = self.skyline[i + covered - 1].clone();
+        let next_x = self.skyline.get(i + covered).map_or(&self.bounds.1.x, |p| &p.x);
+        let mut replacement = vec![Vector2::new(min.x.clone(), max.y.clone())];
+        if *next_x > max.x {
+            replacement.push(Vector2::new(max.x.clone(), last.y));
+        }
+        self.skyline.splice(i..i + covered, replacement);
+        self.skyline.dedup_by(|b, a| b.y == a.y);
+        Some(Rect2(min, max))
+    }
+
+    /// Packs each size in `sizes` into as many containers covering `bounds` as are needed,
+    /// opening a new container whenever a rectangle does not fit into any open container. Returns
+    /// the index of the container and the placement of each rectangle, or `None` if any size is
+    /// not positive or does not fit into an empty container.
+    pub fn pack_all<I>(bounds: Rect2<T>, sizes: I) -> Option<Vec<(usize, Rect2<T>)>>
+    where I: IntoIterator<Item = Vector2<T>>
+    {
+        let mut bins: Vec<SkylinePacker<T>> = Vec::new();
+        let mut placements = Vec::new();
+        for size in sizes {
+            let placed = bins.iter_mut()
+                .enumerate()
+                .find_map(|(i, bin)| Some((i, bin.insert(size.clone())?)));
+            let placement = match placed {
+                Some(placement) => placement,
+                None => {
+                    let mut bin = SkylinePacker::new(bounds.clone());
+                    let rect = bin.insert(size)?;
+                    bins.push(bin);
+                    (bins.len() - 1, rect)
+                },
+            };
+            placements.push(placement);
+        }
+        Some(placements)
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_skyline_packer() {
+    let mut packer = SkylinePacker::new(Rect2::new(0, 0, 10, 10));
+    assert_eq!(packer.insert(Vector2::new(4, 6)), Some(Rect2::new(0, 0, 4, 6)));
+    assert_eq!(packer.insert(Vector2::new(6, 3)), Some(Rect2::new(4, 0, 10, 3)));
+    assert_eq!(packer.insert(Vector2::new(3, 3)), Some(Rect2::new(4, 3, 7, 6)));
+    assert_eq!(packer.insert(Vector2::new(5, 5)), None);
+    assert_eq!(packer.insert(Vector2::new(10, 4)), Some(Rect2::new(0, 6, 10, 10)));
+    assert_eq!(packer.insert(Vector2::new(0, 1)), None);
+
+    let packed = SkylinePacker::pack_all(Rect2::new(0, 0, 4, 4), [Vector2::new(4, 3); 3]).unwrap();
+    assert_eq!(packed.iter().map(|p| p.0).collect::<Vec<_>>(), [0, 1, 2]);
+    assert_eq!(SkylinePacker::pack_all(Rect2::new(0, 0, 4, 4), [Vector2::new(5, 1)]), None);
+}
@@ -0,0 +1,214 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::ops::{BoundingRect, Contains, Orient2d};
+use crate::rect::Rect2;
+use crate::segment::Segment2;
+use crate::vec::Vector2;
+
+/// 2-dimensional polygon structure defined by its vertices in winding order. The polygon is
+/// closed implicitly, so the last vertex connects back to the first. This is only available with
+/// the `alloc` feature.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Polygon2<T>(pub Vec<Vector2<T>>);
+
+impl<T> Polygon2<T> {
+    /// Iterates over the edges of the polygon, including the closing edge from the last vertex to
+    /// the first.
+    pub fn edges(&self) -> impl Iterator<Item = Segment2<T>> + '_
+    where T: Clone
+    {
+        let next = self.0.iter().cycle().skip(1);
+        self.0.iter().zip(next).map(|(a, b)| Segment2(a.clone(), b.clone()))
+    }
+
+    /// Returns true if the polygon has at least three vertices and turns the same way at each
+    /// one. Collinear vertices are allowed. The polygon is assumed not to intersect itself.
+    pub fn is_convex(&self) -> bool
+    where Vector2<T>: Orient2d
+    {
+        let n = self.0.len();
+        if n < 3 {
+            return false;
+        }
+        let mut turn = Ordering::Equal;
+        for i in 0..n {
+            let (a, b, c) = (&self.0[i], &self.0[(i + 1) % n], &self.0[(i + 2) % n]);
+            match a.orient2d(b, c) {
+                None => return false,
+                Some(Ordering::Equal) => {},
+                Some(o) if turn == Ordering::Equal => turn = o,
+                Some(o) if o != turn => return false,
+                Some(_) => {},
+            }
+        }
+        turn != Ordering::Equal
+    }
+}
+
+impl<T> BoundingRect for Polygon2<T>
+where T: Clone + PartialOrd
+{
+    type Output = Option<Rect2<T>>;
+
+    /// Returns the bounding rectangle of the vertices, or `None` if there are none.
+    fn bounding_rect(&self) -> Option<Rect2<T>> {
+        self.0.iter().cloned().collect()
+    }
+}
+
+/// Computes the convex hull of a set of points using Andrew's monotone chain algorithm. The hull
+/// is returned counter-clockwise (assuming the y axis points up), starting from the point with
+/// the least X (and then the least Y), without collinear or duplicate points. Points which cannot
+/// be ordered, such as those with NaN components, produce an unspecified but valid subset. This
+/// is only available with the `alloc` feature.
+pub fn convex_hull<T>(points: &[Vector2<T>]) -> Vec<Vector2<T>>
+where T: Clone + PartialOrd,
+      Vector2<T>: Orient2d
+{
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x).unwrap_or(Ordering::Equal)
+            .then_with(|| a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+    });
+    sorted.dedup_by(|a, b| a.x == b.x && a.y == b.y);
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    // Build the lower hull forward and the upper hull backward, dropping every point which does
+    // not make a counter-clockwise turn.
+    let mut hull: Vec<Vector2<T>> = Vec::with_capacity(sorted.len() + 1);
+    let turns_left = |hull: &[Vector2<T>], p: &Vector2<T>| {
+        let n = hull.len();
+        hull[n - 2].orient2d(&hull[n - 1], p) == Some(Ordering::Greater)
+    };
+    for p in &sorted {
+        while hull.len() >= 2 && !turns_left(&hull, p) {
+            hull.pop();
+        }
+        hull.push(p.clone());
+    }
+    let lower_len = hull.len() + 1;
+    for p in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len && !turns_left(&hull, p) {
+            hull.pop();
+        }
+        hull.push(p.clone());
+    }
+
+    // The upper hull ends with the first point, which is already at the start.
+    hull.pop();
+    hull
+}
+
+//--------------------------------------------------------------------------------------------------
+
+/// Implements functions for polygons with floating point components.
+macro_rules! impl_float {
+    { $($t:ident),* } => { $(
+        impl Polygon2<$t> {
+            /// Returns the centroid (center of mass) of the area enclosed by the polygon, or
+            /// `None` if the polygon encloses no area. The polygon is assumed not to intersect
+            /// itself.
+            pub fn centroid(&self) -> Option<Vector2<$t>> {
+                let (mut area, mut sum) = (0.0, Vector2::new(0.0, 0.0));
+                for Segment2(a, b) in self.edges() {
+                    let cross = a.x * b.y - b.x * a.y;
+                    area += cross;
+                    sum += (a + b) * cross;
+                }
+                if area == 0.0 {
+                    return None;
+                }
+                Some(sum / (area * 3.0))
+            }
+
+            /// Returns the area enclosed by the polygon, which is positive if its vertices wind
+            /// counter-clockwise (assuming the y axis points up) and negative if they wind
+            /// clockwise. Regions of a self-intersecting polygon are counted once per winding.
+            pub fn signed_area(&self) -> $t {
+                self.edges().map(|Segment2(a, b)| a.x * b.y - b.x * a.y).sum::<$t>() * 0.5
+            }
+
+            /// Returns the direction in which the vertices wind, with the same meaning as
+            /// [Orient2d]: `Greater` for counter-clockwise, `Less` for clockwise, and `Equal` if
+            /// the polygon encloses no area.
+            pub fn winding(&self) -> Option<Ordering> {
+                self.signed_area().partial_cmp(&0.0)
+            }
+        }
+
+        impl Contains<Vector2<$t>> for Polygon2<$t> {
+            /// Tests whether `rhs` lies inside the polygon or on its edges, using the even-odd
+            /// rule for self-intersecting polygons.
+            fn contains(&self, rhs: &Vector2<$t>) -> bool {
+                let mut inside = false;
+                for Segment2(a, b) in self.edges() {
+                    let on_edge = a.orient2d(&b, rhs) == Some(Ordering::Equal)
+                        && Rect2(a.min(b), a.max(b)).contains_point_inclusive(rhs);
+                    if on_edge {
+                        return true;
+                    }
+                    if (a.y > rhs.y) != (b.y > rhs.y) {
+                        let x = a.x + (rhs.y - a.y) / (b.y - a.y) * (b.x - a.x);
+                        if rhs.x < x {
+                            inside = !inside;
+                        }
+                    }
+                }
+                inside
+            }
+        }
+    )* };
+}
+
+impl_float!(f32, f64);
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_polygon() {
+    let v = Vector2::new;
+    let square = Polygon2(alloc::vec![v(0.0f64, 0.0), v(4.0, 0.0), v(4.0, 4.0), v(0.0, 4.0)]);
+    assert_eq!(square.signed_area(), 16.0);
+    assert_eq!(square.winding(), Some(Ordering::Greater));
+    assert_eq!(square.centroid(), Some(v(2.0, 2.0)));
+    assert!(square.is_convex());
+    assert!(square.contains(&v(1.0, 3.0)));
+    assert!(square.contains(&v(4.0, 2.0)));
+    assert!(!square.contains(&v(5.0, 2.0)));
+    assert_eq!(square.bounding_rect(), Some(Rect2::new(0.0, 0.0, 4.0, 4.0)));
+
+    let notch = Polygon2(alloc::vec![v(0.0f64, 0.0), v(0.0, 4.0), v(2.0, 2.0), v(4.0, 4.0),
+                                     v(4.0, 0.0)]);
+    assert_eq!(notch.signed_area(), -12.0);
+    assert!(!notch.is_convex());
+    assert!(!notch.contains(&v(2.0, 3.0)));
+    assert!(notch.contains(&v(2.0, 1.0)));
+    let v = Vector2::<i32>::new;
+    assert!(Polygon2(alloc::vec![v(0, 0), v(1, 0), v(0, 1)]).is_convex());
+    assert_eq!(Polygon2::<f32>(alloc::vec![]).bounding_rect(), None);
+}
+
+#[test]
+fn test_convex_hull() {
+    let v = Vector2::<i32>::new;
+    let points = [v(0, 0), v(2, 2), v(4, 0), v(1, 1), v(4, 4), v(0, 4), v(2, 0), v(4, 4)];
+    assert_eq!(convex_hull(&points), [v(0, 0), v(4, 0), v(4, 4), v(0, 4)]);
+    assert_eq!(convex_hull(&[v(0, 0), v(1, 1), v(2, 2)]), [v(0, 0), v(2, 2)]);
+    assert_eq!(convex_hull(&[v(3, 1), v(3, 1)]), [v(3, 1)]);
+    let hull = convex_hull(&[Vector2::new(0.0f64, 0.0), Vector2::new(1.0, 0.5),
+                             Vector2::new(2.0, 0.0), Vector2::new(1.0, 2.0)]);
+    assert_eq!(hull.len(), 3);
+    assert!(Polygon2(hull).is_convex());
+}
@@ -0,0 +1,82 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::cmp::Ordering;
+
+use crate::ops::{BoundingRect, Contains, Orient2d};
+use crate::rect::Rect2;
+use crate::vec::Vector2;
+
+/// 2-dimensional quadrilateral structure defined by its four vertices in winding order. This is
+/// where a [Rect2] lands once it has been rotated, sheared, or otherwise transformed so that it
+/// is no longer axis-aligned.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Quad2<T>(pub Vector2<T>, pub Vector2<T>, pub Vector2<T>, pub Vector2<T>);
+
+impl<T> Quad2<T> {
+    /// Applies `f` to each vertex, as when transforming the quad into another coordinate space.
+    pub fn map_points<U, F>(self, mut f: F) -> Quad2<U>
+    where F: FnMut(Vector2<T>) -> Vector2<U>
+    {
+        Quad2(f(self.0), f(self.1), f(self.2), f(self.3))
+    }
+}
+
+impl<T> BoundingRect for Quad2<T>
+where T: Clone + PartialOrd
+{
+    type Output = Rect2<T>;
+
+    fn bounding_rect(&self) -> Rect2<T> {
+        let min = self.0.clone().min(self.1.clone()).min(self.2.clone()).min(self.3.clone());
+        let max = self.0.clone().max(self.1.clone()).max(self.2.clone()).max(self.3.clone());
+        Rect2(min, max)
+    }
+}
+
+impl<T> Contains<Vector2<T>> for Quad2<T>
+where Vector2<T>: Orient2d
+{
+    /// Tests whether `rhs` lies inside the quad or on its edges, regardless of the winding order
+    /// of the quad's vertices. The quad is assumed to be convex, which holds for any rectangle
+    /// under an affine transform. This is exact for integer components.
+    fn contains(&self, rhs: &Vector2<T>) -> bool {
+        let sides = [
+            self.0.orient2d(&self.1, rhs),
+            self.1.orient2d(&self.2, rhs),
+            self.2.orient2d(&self.3, rhs),
+            self.3.orient2d(&self.0, rhs),
+        ];
+        if sides.contains(&None) {
+            return false;
+        }
+        !(sides.contains(&Some(Ordering::Less)) && sides.contains(&Some(Ordering::Greater)))
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_quad() {
+    let q = Rect2::new(0, 0, 4, 2).to_quad().map_points(|v| Vector2::new(v.x - v.y, v.x + v.y));
+    assert_eq!((q.1, q.2, q.3), (Vector2::new(4, 4), Vector2::new(2, 6), Vector2::new(-2, 2)));
+    assert_eq!(q.bounding_rect(), Rect2::new(-2, 0, 4, 6));
+    assert!(q.contains(&Vector2::new(1, 3)));
+    assert!(q.contains(&Vector2::new(2, 2)));
+    assert!(!q.contains(&Vector2::new(3, 1)));
+    assert!(!q.contains(&Vector2::new(-2, 0)));
+
+    // Unsigned and extreme components must neither underflow nor overflow.
+    let q = Rect2::new(2u32, 2, 6, 4).to_quad();
+    assert!(q.contains(&Vector2::new(3, 3)));
+    assert!(!q.contains(&Vector2::new(1, 3)));
+    let q = Rect2::new(i32::MIN, i32::MIN, i32::MAX, i32::MAX).to_quad();
+    assert!(q.contains(&Vector2::new(0, 0)));
+    assert!(q.contains(&Vector2::new(i32::MAX, i32::MIN)));
+}
@@ -0,0 +1,73 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::vec::Vector2;
+
+/// Fills a polygon with the even-odd rule and returns the covered integer points as horizontal
+/// spans, each paired with its Y coordinate, in order of increasing Y and then X. The polygon is
+/// closed implicitly, and its edges may cross. Points on the minimum X or Y edge of a span are
+/// covered, while points on the maximum edge are not, so polygons which share an edge never both
+/// cover a point on it. This is consistent with
+/// [Triangle2::rasterize](crate::Triangle2::rasterize). This is only available with the `alloc`
+/// feature.
+pub fn scanline_spans(polygon: &[Vector2<i32>]) -> Vec<(i32, Range<i32>)> {
+    let mut spans = Vec::new();
+    let Some(min_y) = polygon.iter().map(|p| p.y).min() else { return spans };
+    let max_y = polygon.iter().map(|p| p.y).max().unwrap_or(min_y);
+    let mut crossings = Vec::new();
+
+    for y in min_y..max_y {
+        crossings.clear();
+        for (i, a) in polygon.iter().enumerate() {
+            let b = polygon[(i + 1) % polygon.len()];
+            let (a, b) = if a.y <= b.y { (*a, b) } else { (b, *a) };
+            if !(a.y <= y && y < b.y) {
+                continue;
+            }
+            // Round the crossing up to the first integer X at or after it. Each difference may
+            // need 33 bits, so their product is only safe in `i128`.
+            let num = (y as i128 - a.y as i128) * (b.x as i128 - a.x as i128);
+            let den = b.y as i128 - a.y as i128;
+            crossings.push(a.x as i128 - (-num).div_euclid(den));
+        }
+        crossings.sort_unstable();
+        for pair in crossings.chunks_exact(2) {
+            if pair[0] < pair[1] {
+                spans.push((y, pair[0] as i32..pair[1] as i32));
+            }
+        }
+    }
+
+    spans
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_scanline_spans() {
+    let v = Vector2::new;
+    assert_eq!(scanline_spans(&[v(0, 0), v(3, 0), v(0, 3)]), [(0, 0..3), (1, 0..2), (2, 0..1)]);
+
+    // A U shape is split into two spans on the rows of its prongs.
+    let u = [v(0, 0), v(1, 0), v(1, 2), v(2, 2), v(2, 0), v(3, 0), v(3, 3), v(0, 3)];
+    assert_eq!(scanline_spans(&u), [(0, 0..1), (0, 2..3), (1, 0..1), (1, 2..3), (2, 0..3)]);
+
+    let triangle = crate::Triangle2(v(1, -2), v(7, 3), v(-3, 5));
+    let cells = scanline_spans(&[triangle.0, triangle.1, triangle.2]).into_iter()
+        .flat_map(|(y, xs)| xs.map(move |x| v(x, y)));
+    assert!(cells.eq(triangle.rasterize()));
+    assert!(scanline_spans(&[]).is_empty());
+
+    let wide = [v(i32::MIN, 0), v(i32::MAX, 0), v(i32::MAX, 2)];
+    assert_eq!(scanline_spans(&wide), [(0, i32::MIN..i32::MAX), (1, 0..i32::MAX)]);
+    let tall = [v(0, i32::MIN), v(1, i32::MIN), v(0, i32::MIN + 2)];
+    assert_eq!(scanline_spans(&tall), [(i32::MIN, 0..1), (i32::MIN + 1, 0..1)]);
+}
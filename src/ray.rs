@@ -0,0 +1,131 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::ops::{Add, Mul};
+
+#[cfg(feature = "std")]
+use crate::circle::Sphere;
+use crate::ops::{Cross, Dot};
+use crate::triangle::Triangle3;
+use crate::vec::Vector3;
+
+/// 3-dimensional ray structure defined by its origin and direction. The direction does not need
+/// to be normalized; parameters along the ray are measured in multiples of it.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ray3<T> {
+    pub origin: Vector3<T>,
+    pub direction: Vector3<T>,
+}
+
+impl<T> Ray3<T> {
+    /// Constructs a new ray from its origin and direction.
+    pub const fn new(origin: Vector3<T>, direction: Vector3<T>) -> Ray3<T> {
+        Ray3 { origin, direction }
+    }
+
+    /// Returns the point at parameter `t` along the ray, which is `origin + direction * t`.
+    pub fn point_at(&self, t: T) -> Vector3<T>
+    where T: Add<Output = T> + Clone + Mul<Output = T>
+    {
+        self.origin.clone() + self.direction.clone() * t
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+/// Implements functions for rays with floating point components.
+macro_rules! impl_float {
+    { $($t:ident),* } => { $(
+        impl Ray3<$t> {
+            /// Returns the parameter at which the ray first enters `sphere`, or the parameter at
+            /// which it leaves if the origin is inside the sphere. Returns `None` if the ray
+            /// misses the sphere or if the sphere lies entirely behind the origin.
+            #[cfg(feature = "std")]
+            pub fn intersect_sphere(&self, sphere: &Sphere<$t>) -> Option<$t> {
+                let oc = self.origin - sphere.center;
+                let a = self.direction.dot(self.direction);
+                let b = oc.dot(self.direction);
+                let c = oc.dot(oc) - sphere.radius * sphere.radius;
+                let discriminant = b * b - a * c;
+                if discriminant < 0.0 || a == 0.0 {
+                    return None;
+                }
+                let root = discriminant.sqrt();
+                [(-b - root) / a, (-b + root) / a].into_iter().find(|&t| t >= 0.0)
+            }
+
+            /// Intersects the ray with either side of `triangle` using the Möller-Trumbore
+            /// algorithm. Returns the parameter of the hit along the ray and the barycentric
+            /// coordinates of the hit within the triangle, as for
+            /// [Triangle3::barycentric](crate::Triangle3::barycentric). Returns `None` if the ray
+            /// misses, if the triangle is behind the origin, or if the ray is exactly parallel to
+            /// the triangle's plane.
+            pub fn intersect_triangle(&self, triangle: &Triangle3<$t>)
+                -> Option<($t, Vector3<$t>)>
+            {
+                let Triangle3(a, b, c) = *triangle;
+                let (e1, e2) = (b - a, c - a);
+                let p = self.direction.cross(e2);
+                let det = e1.dot(p);
+                if det == 0.0 {
+                    return None;
+                }
+                let inv_det = 1.0 / det;
+
+                let s = self.origin - a;
+                let u = s.dot(p) * inv_det;
+                if !(0.0..=1.0).contains(&u) {
+                    return None;
+                }
+                let q = s.cross(e1);
+                let v = self.direction.dot(q) * inv_det;
+                if v < 0.0 || u + v > 1.0 {
+                    return None;
+                }
+                let t = e2.dot(q) * inv_det;
+                if t < 0.0 {
+                    return None;
+                }
+                Some((t, Vector3::new(1.0 - u - v, u, v)))
+            }
+        }
+    )* };
+}
+
+impl_float!(f32, f64);
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_ray_triangle() {
+    let ray = Ray3::new(Vector3::new(1.0f64, 1.0, 5.0), Vector3::new(0.0, 0.0, -2.0));
+    let tri = Triangle3(Vector3::new(0.0, 0.0, 1.0), Vector3::new(4.0, 0.0, 1.0),
+                        Vector3::new(0.0, 4.0, 1.0));
+    let (t, bary) = ray.intersect_triangle(&tri).unwrap();
+    assert_eq!(t, 2.0);
+    assert_eq!(ray.point_at(t), Vector3::new(1.0, 1.0, 1.0));
+    assert_eq!(bary, Vector3::new(0.5, 0.25, 0.25));
+    let flipped = Triangle3(tri.0, tri.2, tri.1);
+    assert_eq!(ray.intersect_triangle(&flipped).map(|hit| hit.0), Some(2.0));
+    assert_eq!(Ray3::new(ray.origin, -ray.direction).intersect_triangle(&tri), None);
+    assert_eq!(Ray3::new(Vector3::new(5.0, 5.0, 5.0), ray.direction).intersect_triangle(&tri),
+               None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_ray_sphere() {
+    let sphere = Sphere::new(Vector3::new(0.0f32, 0.0, 0.0), 1.0);
+    let hit = |x: f32, y, dx| Ray3::new(Vector3::new(x, y, 0.0), Vector3::new(dx, 0.0, 0.0))
+        .intersect_sphere(&sphere);
+    assert_eq!(hit(-3.0, 0.0, 1.0), Some(2.0));
+    assert_eq!(hit(0.0, 0.0, 1.0), Some(1.0));
+    assert_eq!(hit(-3.0, 0.0, -1.0), None);
+    assert_eq!(hit(-3.0, 2.0, 1.0), None);
+}
@@ -0,0 +1,74 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::rect::{Rect2, Rect3};
+#[cfg(test)]
+use crate::vec::Vector2;
+
+/// Implements operations over collections of rectangles which need to allocate. These are only
+/// available with the `alloc` feature.
+macro_rules! impl_batch {
+    { $($rect:ident),* } => { $(
+        impl<T> $rect<T> {
+            /// Returns the index pairs `(i, j)`, where `i < j`, of all rectangles in `rects` which
+            /// [intersect](Self::intersects), sorted in ascending order. This sorts the
+            /// rectangles along the X axis and sweeps across them, so only rectangles which
+            /// overlap on the X axis are compared. Rectangles which are not positive never
+            /// overlap anything.
+            pub fn overlapping_pairs(rects: &[$rect<T>]) -> Vec<(usize, usize)>
+            where T: PartialOrd
+            {
+                let mut order: Vec<usize> = (0..rects.len())
+                    .filter(|&i| rects[i].is_partially_positive())
+                    .collect();
+                order.sort_unstable_by(|&a, &b| {
+                    rects[a].0.x.partial_cmp(&rects[b].0.x).unwrap_or(Ordering::Equal)
+                });
+
+                let mut active: Vec<usize> = Vec::new();
+                let mut pairs = Vec::new();
+                for i in order {
+                    let rect = &rects[i];
+                    active.retain(|&j| rects[j].1.x > rect.0.x);
+                    for &j in active.iter() {
+                        if rects[j].intersects(rect) {
+                            pairs.push((i.min(j), i.max(j)));
+                        }
+                    }
+                    active.push(i);
+                }
+                pairs.sort_unstable();
+                pairs
+            }
+        }
+    )* };
+}
+
+impl_batch!(Rect2, Rect3);
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_overlapping_pairs() {
+    let rects = [
+        Rect2::new(0, 0, 4, 4),
+        Rect2::new(10, 0, 12, 2),
+        Rect2::new(3, 3, 11, 5),
+        Rect2::new(1, 10, 2, 11),
+        Rect2::new(4, 0, 6, 4),
+        Rect2::new(2, 2, 2, 8),
+    ];
+    assert_eq!(Rect2::overlapping_pairs(&rects), [(0, 2), (2, 4)]);
+    let size = Vector2::new(2, 2);
+    let grid: Vec<_> = (0..4).map(|i| Rect2::from_origin_size(Vector2::new(i, i), size)).collect();
+    assert_eq!(Rect2::overlapping_pairs(&grid), [(0, 1), (1, 2), (2, 3)]);
+    assert!(Rect3::<f32>::overlapping_pairs(&[]).is_empty());
+}
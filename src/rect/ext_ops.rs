@@ -54,23 +54,23 @@ macro_rules! impl_unary_ops {
 macro_rules! impl_scalar_ops {
     { $(impl $trait:ident::$fn:ident for $rect:ident;)* } => { $(
         impl<T> $trait<T> for $rect<T>
-        where T: Copy + $trait
+        where T: Clone + $trait
         {
             type Output = $rect<<T as $trait>::Output>;
 
             fn $fn(self, rhs: T) -> Self::Output {
-                $rect($trait::$fn(self.0, rhs), $trait::$fn(self.1, rhs))
+                $rect($trait::$fn(self.0, rhs.clone()), $trait::$fn(self.1, rhs.clone()))
             }
         }
 
         impl<'a, T> $trait<T> for &'a $rect<T>
-        where T: Copy,
+        where T: Clone,
               &'a T: $trait<T>
         {
             type Output = $rect<<&'a T as $trait<T>>::Output>;
 
             fn $fn(self, rhs: T) -> Self::Output {
-                $rect($trait::$fn(&self.0, rhs), $trait::$fn(&self.1, rhs))
+                $rect($trait::$fn(&self.0, rhs.clone()), $trait::$fn(&self.1, rhs.clone()))
             }
         }
 
@@ -100,23 +100,23 @@ macro_rules! impl_scalar_ops {
 macro_rules! impl_vec_ops {
     { $(impl $trait:ident::$fn:ident for $rect:ident: $vec:ident;)* } => { $(
         impl<T> $trait<$vec<T>> for $rect<T>
-        where T: Copy + $trait
+        where T: Clone + $trait
         {
             type Output = $rect<<T as $trait>::Output>;
 
             fn $fn(self, rhs: $vec<T>) -> Self::Output {
-                $rect($trait::$fn(self.0, rhs), $trait::$fn(self.1, rhs))
+                $rect($trait::$fn(self.0, rhs.clone()), $trait::$fn(self.1, rhs.clone()))
             }
         }
 
         impl<'a, T> $trait<$vec<T>> for &'a $rect<T>
-        where T: Copy,
+        where T: Clone,
               &'a T: $trait<T>
         {
             type Output = $rect<<&'a T as $trait<T>>::Output>;
 
             fn $fn(self, rhs: $vec<T>) -> Self::Output {
-                $rect($trait::$fn(&self.0, rhs), $trait::$fn(&self.1, rhs))
+                $rect($trait::$fn(&self.0, rhs.clone()), $trait::$fn(&self.1, rhs.clone()))
             }
         }
 
@@ -173,25 +173,25 @@ macro_rules! impl_try_unary_ops {
 macro_rules! impl_try_scalar_ops {
     { $(impl $trait:ident::$fn:ident for $rect:ident;)* } => { $(
         impl<T> $trait<T> for $rect<T>
-        where T: Copy + $trait
+        where T: Clone + $trait
         {
             type Output = $rect<<T as $trait>::Output>;
             type Error = <T as $trait>::Error;
 
             fn $fn(self, rhs: T) -> Result<Self::Output, Self::Error> {
-                Ok($rect($trait::$fn(self.0, rhs)?, $trait::$fn(self.1, rhs)?))
+                Ok($rect($trait::$fn(self.0, rhs.clone())?, $trait::$fn(self.1, rhs.clone())?))
             }
         }
 
         impl<'a, T> $trait<T> for &'a $rect<T>
-        where T: Copy,
+        where T: Clone,
               &'a T: $trait<T>
         {
             type Output = $rect<<&'a T as $trait<T>>::Output>;
             type Error = <&'a T as $trait<T>>::Error;
 
             fn $fn(self, rhs: T) -> Result<Self::Output, Self::Error> {
-                Ok($rect($trait::$fn(&self.0, rhs)?, $trait::$fn(&self.1, rhs)?))
+                Ok($rect($trait::$fn(&self.0, rhs.clone())?, $trait::$fn(&self.1, rhs.clone())?))
             }
         }
 
@@ -223,25 +223,25 @@ macro_rules! impl_try_scalar_ops {
 macro_rules! impl_try_vec_ops {
     { $(impl $trait:ident::$fn:ident for $rect:ident: $vec:ident;)* } => { $(
         impl<T> $trait<$vec<T>> for $rect<T>
-        where T: Copy + $trait
+        where T: Clone + $trait
         {
             type Output = $rect<<T as $trait>::Output>;
             type Error = <T as $trait>::Error;
 
             fn $fn(self, rhs: $vec<T>) -> Result<Self::Output, Self::Error> {
-                Ok($rect($trait::$fn(self.0, rhs)?, $trait::$fn(self.1, rhs)?))
+                Ok($rect($trait::$fn(self.0, rhs.clone())?, $trait::$fn(self.1, rhs.clone())?))
             }
         }
 
         impl<'a, T> $trait<$vec<T>> for &'a $rect<T>
-        where T: Copy,
+        where T: Clone,
               &'a T: $trait<T>
         {
             type Output = $rect<<&'a T as $trait<T>>::Output>;
             type Error = <&'a T as $trait<T>>::Error;
 
             fn $fn(self, rhs: $vec<T>) -> Result<Self::Output, Self::Error> {
-                Ok($rect($trait::$fn(&self.0, rhs)?, $trait::$fn(&self.1, rhs)?))
+                Ok($rect($trait::$fn(&self.0, rhs.clone())?, $trait::$fn(&self.1, rhs.clone())?))
             }
         }
 
@@ -0,0 +1,264 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+#[cfg(feature = "std")]
+use crate::convert::{RoundingMode, SaturatingFrom};
+#[cfg(feature = "std")]
+use crate::ops::{Distance, InnerSpace, L2, Norm};
+use crate::align::Align;
+use crate::rect::{Rect2, Rect3};
+use crate::vec::{Vector2, Vector3};
+
+/// Implements functions for rectangles with floating point components. Functions which depend on
+/// the platform's math library are only available with the `std` feature.
+macro_rules! impl_float {
+    { $(impl $rect:ident<$t:ident>: $vec:ident($($field:ident),*);)* } => { $(
+        impl $rect<$t> {
+            /// Inverted rectangle spanning from positive to negative infinity. It is the identity
+            /// for [union](Self::union) and for extending with points, so it is a suitable
+            /// starting value when accumulating bounds.
+            pub const EMPTY: $rect<$t> = $rect($vec { $($field: $t::INFINITY),* },
+                                               $vec { $($field: $t::NEG_INFINITY),* });
+
+            impl_aligned_within! {
+                /// Moves the rectangle, keeping its size, so that it is aligned with `container` on
+                /// each axis according to `align`, which may be an [Anchor](crate::Anchor). Both
+                /// rectangles are assumed to be ordered.
+                $rect<$t>: $vec($($field),*)
+            }
+
+            /// Returns the center of the rectangle. Each point is halved before summing so that the
+            /// result does not overflow.
+            pub fn center(&self) -> $vec<$t> {
+                self.0 * 0.5 + self.1 * 0.5
+            }
+
+            /// Converts the rectangle to another numeric type after rounding it inward with
+            /// [round_in](Self::round_in), so that the result only covers pixels which are
+            /// entirely covered by `self`. Values which are out of range for the target type are
+            /// clamped to its bounds.
+            #[cfg(feature = "std")]
+            pub fn convert_round_in<U>(self) -> $rect<U>
+            where U: SaturatingFrom<$t>
+            {
+                self.round_in().saturating_convert()
+            }
+
+            /// Converts the rectangle to another numeric type after rounding it outward with
+            /// [round_out](Self::round_out), so that the result covers every pixel which is even
+            /// partly covered by `self`. Values which are out of range for the target type are
+            /// clamped to its bounds.
+            #[cfg(feature = "std")]
+            pub fn convert_round_out<U>(self) -> $rect<U>
+            where U: SaturatingFrom<$t>
+            {
+                self.round_out().saturating_convert()
+            }
+
+            /// Returns the smallest rectangle that includes `self` and `rhs`, assuming that both
+            /// rectangles are ordered. `NaN` components are ignored, so a rectangle with some
+            /// `NaN` components does not corrupt the result.
+            pub fn expand_ignore_nan(self, rhs: $rect<$t>) -> $rect<$t> {
+                $rect(self.0.min_ignore_nan(rhs.0), self.1.max_ignore_nan(rhs.1))
+            }
+
+            /// Returns the smallest rectangle that includes `self` and `rhs`, assuming that both
+            /// rectangles are ordered. If either of a pair of corresponding components is `NaN`,
+            /// the result for that component is `NaN`, so that invalid input remains detectable.
+            pub fn expand_propagate_nan(self, rhs: $rect<$t>) -> $rect<$t> {
+                $rect(self.0.min_propagate_nan(rhs.0), self.1.max_propagate_nan(rhs.1))
+            }
+
+            /// Constructs a rectangle of the given size centered on `center`.
+            pub fn from_center_size(center: $vec<$t>, size: $vec<$t>) -> $rect<$t> {
+                $rect::from_center_half_extents(center, size * 0.5)
+            }
+
+            /// Divides the rectangle into a grid with `counts.x` columns, `counts.y` rows and so
+            /// on, and iterates over the cells in row-major order. All cells have the same size,
+            /// and the outer edges of the grid coincide exactly with the rectangle. Nothing is
+            /// yielded if the total number of cells overflows `usize`.
+            pub fn grid_cells(self, counts: $vec<usize>) -> impl Iterator<Item = $rect<$t>> {
+                let edge = move |index: $vec<usize>| $vec { $($field: {
+                    let (min, max, n) = (self.0.$field, self.1.$field, counts.$field);
+                    if index.$field >= n {
+                        max
+                    } else {
+                        min + (max - min) * (index.$field as $t / n as $t)
+                    }
+                }),* };
+                let total = [$(counts.$field),*].into_iter().try_fold(1, usize::checked_mul);
+                (0..total.unwrap_or(0)).map(move |mut k| {
+                    let index = $vec { $($field: {
+                        let i = k % counts.$field;
+                        k /= counts.$field;
+                        i
+                    }),* };
+                    $rect(edge(index), edge($vec { $($field: index.$field + 1),* }))
+                })
+            }
+
+            /// Returns half of the rectangle's size, which is the distance from its center to each
+            /// of its faces.
+            pub fn half_extents(&self) -> $vec<$t> {
+                (self.1 - self.0) * 0.5
+            }
+
+            /// Rounds `self.0` up and `self.1` down to the nearest integers, producing the
+            /// largest integral rectangle contained by `self`, assuming `self` is ordered. The
+            /// result may not be ordered if `self` does not contain such a rectangle.
+            #[cfg(feature = "std")]
+            pub fn round_in(self) -> $rect<$t> {
+                $rect(self.0.ceil(), self.1.floor())
+            }
+
+            /// Rounds `self.0` down and `self.1` up to the nearest integers, producing the
+            /// smallest integral rectangle that contains `self`, assuming `self` is ordered.
+            #[cfg(feature = "std")]
+            pub fn round_out(self) -> $rect<$t> {
+                $rect(self.0.floor(), self.1.ceil())
+            }
+
+            /// Returns the signed Euclidean distance from `point` to the boundary of the rectangle,
+            /// which is assumed to be ordered. The result is negative if `point` lies inside the
+            /// rectangle, as in a signed distance field.
+            #[cfg(feature = "std")]
+            pub fn signed_distance(&self, point: $vec<$t>) -> $t {
+                let q = (point - self.center()).abs() - self.half_extents();
+                q.max($vec::default()).norm(L2) + q.max_element().min(0.0)
+            }
+
+            /// Snaps `self.0` down and `self.1` up to multiples of `step`, producing the smallest
+            /// rectangle aligned to the grid that contains `self`, assuming `self` is ordered.
+            #[cfg(feature = "std")]
+            pub fn snap_to_grid(self, step: $vec<$t>) -> $rect<$t> {
+                $rect(self.0.snap_to(step, RoundingMode::Floor),
+                      self.1.snap_to(step, RoundingMode::Ceil))
+            }
+
+            /// Snaps `self.0` up and `self.1` down to multiples of `step`, producing the largest
+            /// rectangle aligned to the grid that is contained by `self`, assuming `self` is
+            /// ordered. The result may not be ordered if `self` does not contain a grid cell.
+            #[cfg(feature = "std")]
+            pub fn snap_to_grid_in(self, step: $vec<$t>) -> $rect<$t> {
+                $rect(self.0.snap_to(step, RoundingMode::Ceil),
+                      self.1.snap_to(step, RoundingMode::Floor))
+            }
+        }
+
+        impl $vec<$t> {
+            /// Wraps each component around the half-open range covered by `bounds`, which is
+            /// assumed to be positive, as in a world where leaving one edge re-enters at the
+            /// opposite edge.
+            #[cfg(feature = "std")]
+            pub fn wrap_into(self, bounds: &$rect<$t>) -> $vec<$t> {
+                bounds.0 + (self - bounds.0).rem_euclid(bounds.1 - bounds.0)
+            }
+        }
+
+        /// Distance from a rectangle, which is assumed to be ordered, to a point.
+        #[cfg(feature = "std")]
+        impl Distance<$vec<$t>> for $rect<$t> {
+            type Output = $t;
+
+            fn distance_to(&self, rhs: &$vec<$t>) -> $t {
+                self.distance_to_point(*rhs, L2)
+            }
+
+            fn distance_squared_to(&self, rhs: &$vec<$t>) -> $t {
+                self.distance_squared_to_point(*rhs)
+            }
+        }
+
+        /// Distance between two rectangles, which are assumed to be ordered.
+        #[cfg(feature = "std")]
+        impl Distance for $rect<$t> {
+            type Output = $t;
+
+            fn distance_to(&self, rhs: &$rect<$t>) -> $t {
+                self.distance_squared_to(rhs).sqrt()
+            }
+
+            fn distance_squared_to(&self, rhs: &$rect<$t>) -> $t {
+                let gap = (self.0 - rhs.1).max(rhs.0 - self.1).max($vec::default());
+                gap.magnitude_squared()
+            }
+        }
+
+        /// Distance from a point to a rectangle, which is assumed to be ordered.
+        #[cfg(feature = "std")]
+        impl Distance<$rect<$t>> for $vec<$t> {
+            type Output = $t;
+
+            fn distance_to(&self, rhs: &$rect<$t>) -> $t {
+                rhs.distance_to(self)
+            }
+
+            fn distance_squared_to(&self, rhs: &$rect<$t>) -> $t {
+                rhs.distance_squared_to(self)
+            }
+        }
+    )* };
+}
+
+/// Implements dimension-specific functions for rectangles with floating point components.
+macro_rules! impl_float_dim {
+    { $($t:ident),* } => { $(
+        impl Rect2<$t> {
+            /// Returns the ratio of the rectangle's width to its height.
+            pub fn aspect_ratio(&self) -> $t {
+                self.width() / self.height()
+            }
+
+            /// Scales the rectangle, preserving its aspect ratio, so that it covers all of
+            /// `container`, and centers it within `container`. Parts of the result may lie
+            /// outside `container`, as when cropping an image to fill a view. Both rectangles are
+            /// assumed to be ordered and not empty.
+            pub fn fill(&self, container: &Rect2<$t>) -> Rect2<$t> {
+                let scale = (container.width() / self.width())
+                    .max(container.height() / self.height());
+                Rect2::<$t>::from_center_size(container.center(), self.size() * scale)
+            }
+
+            /// Scales the rectangle, preserving its aspect ratio, so that it is as large as
+            /// possible while lying within `container`, and centers it within `container`, as
+            /// when letterboxing an image in a view. Both rectangles are assumed to be ordered and
+            /// not empty.
+            pub fn fit_inside(&self, container: &Rect2<$t>) -> Rect2<$t> {
+                let scale = (container.width() / self.width())
+                    .min(container.height() / self.height());
+                Rect2::<$t>::from_center_size(container.center(), self.size() * scale)
+            }
+
+            /// Splits the rectangle into four equal quadrants which meet at its
+            /// [center](Self::center). See [quadrants_around](Self::quadrants_around) for the
+            /// order of the quadrants.
+            pub fn quadrants(&self) -> [Rect2<$t>; 4] {
+                self.quadrants_around(self.center())
+            }
+        }
+
+        impl Rect3<$t> {
+            /// Splits the prism into eight equal octants which meet at its
+            /// [center](Self::center). See [octants_around](Self::octants_around) for the order
+            /// of the octants.
+            pub fn octants(&self) -> [Rect3<$t>; 8] {
+                self.octants_around(self.center())
+            }
+        }
+    )* };
+}
+
+impl_float! {
+    impl Rect2<f32>: Vector2(x, y);
+    impl Rect2<f64>: Vector2(x, y);
+    impl Rect3<f32>: Vector3(x, y, z);
+    impl Rect3<f64>: Vector3(x, y, z);
+}
+
+impl_float_dim!(f32, f64);
@@ -0,0 +1,420 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::ops::Range;
+
+use crate::align::Align;
+use crate::convert::RoundingMode;
+use crate::rect::{Rect2, Rect3};
+use crate::vec::{Vector2, Vector3};
+
+/// Implements functions for rectangles with integer components. `$u` is the unsigned counterpart of
+/// `$t`.
+macro_rules! impl_int {
+    { $($t:ident => $u:ident),* } => { $(
+        impl_int!(@impl $t => $u: Rect2: Vector2(x, y), Rect3: Vector3(x, y, z));
+
+        impl Rect2<$t> {
+            /// Iterates over the integer points on the edge of the half-open range covered by the
+            /// rectangle, visiting each point once. Iteration starts at `self.0`, proceeds toward
+            /// increasing X along the row at `self.0.y`, and continues around the edge in the same
+            /// direction. Nothing is yielded if the rectangle is not positive.
+            pub fn border_points(&self) -> impl Iterator<Item = Vector2<$t>> {
+                let Rect2(min, max) = *self;
+                self.is_positive().then(move || {
+                    let (right, top) = (max.x - 1, max.y - 1);
+                    let top_end = if min.y < top { right } else { min.x };
+                    let left_end = if min.x < right { top } else { min.y + 1 };
+                    (min.x..max.x).map(move |x| Vector2::new(x, min.y))
+                        .chain((min.y + 1..max.y).map(move |y| Vector2::new(right, y)))
+                        .chain((min.x..top_end).rev().map(move |x| Vector2::new(x, top)))
+                        .chain((min.y + 1..left_end).rev().map(move |y| Vector2::new(min.x, y)))
+                }).into_iter().flatten()
+            }
+
+            /// Returns the area of the rectangle, or `None` if it is not ordered or if the
+            /// computation overflows.
+            pub fn checked_area(&self) -> Option<$t> {
+                let size = self.checked_size()?;
+                size.x.checked_mul(size.y)
+            }
+
+            /// Returns the perimeter of the rectangle, or `None` if it is not ordered or if the
+            /// computation overflows.
+            pub fn checked_perimeter(&self) -> Option<$t> {
+                let size = self.checked_size()?;
+                size.x.checked_add(size.y)?.checked_mul(2)
+            }
+
+            /// Splits the rectangle, which is assumed to be ordered, into four quadrants which
+            /// meet at its [center](Self::center). When a side has odd length, the center is
+            /// rounded down, so the quadrants on its positive side get the extra unit. See
+            /// [quadrants_around](Self::quadrants_around) for the order of the quadrants.
+            pub fn quadrants(&self) -> [Rect2<$t>; 4] {
+                self.quadrants_around(self.center())
+            }
+
+            /// Iterates over the rows of the half-open range covered by the rectangle in order of
+            /// increasing Y, yielding each row's Y coordinate with its range of X coordinates.
+            /// Nothing is yielded if the rectangle is not positive.
+            pub fn rows(&self) -> impl Iterator<Item = ($t, Range<$t>)> {
+                let Rect2(min, max) = *self;
+                let end = if min.x < max.x { max.y } else { min.y };
+                (min.y..end).map(move |y| (y, min.x..max.x))
+            }
+        }
+
+        impl Rect3<$t> {
+            /// Returns the depth of the prism, or `None` if `self.1.z < self.0.z` or if the depth
+            /// overflows.
+            pub fn checked_depth(&self) -> Option<$t> {
+                if self.1.z < self.0.z {
+                    return None;
+                }
+                self.1.z.checked_sub(self.0.z)
+            }
+
+            /// Returns the surface area of the prism, or `None` if it is not ordered or if the
+            /// computation overflows.
+            pub fn checked_surface_area(&self) -> Option<$t> {
+                let Vector3 { x, y, z } = self.checked_size()?;
+                x.checked_mul(y)?
+                    .checked_add(y.checked_mul(z)?)?
+                    .checked_add(z.checked_mul(x)?)?
+                    .checked_mul(2)
+            }
+
+            /// Returns the volume of the prism, or `None` if it is not ordered or if the
+            /// computation overflows.
+            pub fn checked_volume(&self) -> Option<$t> {
+                let size = self.checked_size()?;
+                size.x.checked_mul(size.y)?.checked_mul(size.z)
+            }
+
+            /// Splits the prism, which is assumed to be ordered, into eight octants which meet at
+            /// its [center](Self::center). When a side has odd length, the center is rounded
+            /// down, so the octants on its positive side get the extra unit. See
+            /// [octants_around](Self::octants_around) for the order of the octants.
+            pub fn octants(&self) -> [Rect3<$t>; 8] {
+                self.octants_around(self.center())
+            }
+
+            /// Iterates over the integer points on the surface of the half-open range covered by
+            /// the prism, visiting each point once and skipping the interior entirely. Points are
+            /// yielded in order of increasing Z, then Y, then X. Nothing is yielded if the prism
+            /// is not positive.
+            pub fn shell_points(&self) -> impl Iterator<Item = Vector3<$t>> {
+                let Rect3(min, max) = *self;
+                self.is_positive().then(move || {
+                    let last = max - Vector3::new(1, 1, 1);
+                    (min.z..max.z).flat_map(move |z| (min.y..max.y).flat_map(move |y| {
+                        let full = z == min.z || z == last.z || y == min.y || y == last.y;
+                        (min.x..if full { max.x } else { min.x })
+                            .chain((!full).then_some(min.x))
+                            .chain((!full && min.x < last.x).then_some(last.x))
+                            .map(move |x| Vector3::new(x, y, z))
+                    }))
+                }).into_iter().flatten()
+            }
+        }
+    )* };
+    { @impl $t:ident => $u:ident: $($rect:ident: $vec:ident($($field:ident),*)),* } => { $(
+        impl $rect<$t> {
+            /// Inverted rectangle spanning from the greatest to the least representable point. It
+            /// is the identity for [union](Self::union) and for extending with points, so it is a
+            /// suitable starting value when accumulating bounds.
+            pub const EMPTY: $rect<$t> = $rect($vec { $($field: $t::MAX),* },
+                                               $vec { $($field: $t::MIN),* });
+
+            impl_aligned_within! {
+                /// Moves the rectangle, keeping its size, so that it is aligned with `container` on
+                /// each axis according to `align`, which may be an [Anchor](crate::Anchor). Both
+                /// rectangles are assumed to be ordered. When centering leaves an odd remainder,
+                /// the extra unit is placed on the positive side.
+                $rect<$t>: $vec($($field),*)
+            }
+
+            /// Returns the center of the rectangle, rounded toward negative infinity. This never
+            /// overflows.
+            pub fn center(&self) -> $vec<$t> {
+                $vec { $($field: (self.0.$field & self.1.$field)
+                                 + ((self.0.$field ^ self.1.$field) >> 1)),* }
+            }
+
+            /// Moves each edge of the rectangle inward by `amount`, returning `None` if any
+            /// component overflows.
+            pub fn checked_deflated(self, amount: $t) -> Option<$rect<$t>> {
+                let v = $vec { $($field: amount),* };
+                Some($rect(self.0.checked_add(v)?, self.1.checked_sub(v)?))
+            }
+
+            /// Returns the height of the rectangle, or `None` if `self.1.y < self.0.y` or if the
+            /// height overflows.
+            pub fn checked_height(&self) -> Option<$t> {
+                if self.1.y < self.0.y {
+                    return None;
+                }
+                self.1.y.checked_sub(self.0.y)
+            }
+
+            /// Moves each edge of the rectangle outward by `amount`, returning `None` if any
+            /// component overflows.
+            pub fn checked_inflated(self, amount: $t) -> Option<$rect<$t>> {
+                let v = $vec { $($field: amount),* };
+                Some($rect(self.0.checked_sub(v)?, self.1.checked_add(v)?))
+            }
+
+            /// Returns the size of the rectangle, or `None` if it is not ordered or if the size
+            /// overflows, as for `i32::MIN..i32::MAX`.
+            pub fn checked_size(&self) -> Option<$vec<$t>> {
+                if !self.is_ordered() {
+                    return None;
+                }
+                self.1.checked_sub(self.0)
+            }
+
+            /// Moves the rectangle by `offset`, returning `None` if any component overflows.
+            pub fn checked_translated(self, offset: $vec<$t>) -> Option<$rect<$t>> {
+                Some($rect(self.0.checked_add(offset)?, self.1.checked_add(offset)?))
+            }
+
+            /// Returns the width of the rectangle, or `None` if `self.1.x < self.0.x` or if the
+            /// width overflows.
+            pub fn checked_width(&self) -> Option<$t> {
+                if self.1.x < self.0.x {
+                    return None;
+                }
+                self.1.x.checked_sub(self.0.x)
+            }
+
+            /// Constructs a rectangle of the given size whose [center](Self::center) is `center`.
+            /// When a component of `size` is odd, the extra unit is placed on the positive side.
+            pub fn from_center_size(center: $vec<$t>, size: $vec<$t>) -> $rect<$t> {
+                let min = center - size / 2;
+                $rect(min, min + size)
+            }
+
+            /// Divides the rectangle, which is assumed to be ordered, into a grid with
+            /// `counts.x` columns, `counts.y` rows and so on, and iterates over the cells in
+            /// row-major order. Each cell is `size / count` units wide along each axis, and the
+            /// last cell along each axis also absorbs the remainder. This never overflows, and
+            /// nothing is yielded if the total number of cells overflows `usize`.
+            pub fn grid_cells(self, counts: $vec<usize>) -> impl Iterator<Item = $rect<$t>> {
+                let edge = move |index: $vec<usize>| $vec { $($field: {
+                    let (min, max, n) = (self.0.$field, self.1.$field, counts.$field);
+                    if index.$field >= n {
+                        max
+                    } else {
+                        let step = $u::try_from(n).map_or(0, |n| max.abs_diff(min) / n);
+                        min.wrapping_add((step * index.$field as $u) as $t)
+                    }
+                }),* };
+                let total = [$(counts.$field),*].into_iter().try_fold(1, usize::checked_mul);
+                (0..total.unwrap_or(0)).map(move |mut k| {
+                    let index = $vec { $($field: {
+                        let i = k % counts.$field;
+                        k /= counts.$field;
+                        i
+                    }),* };
+                    $rect(edge(index), edge($vec { $($field: index.$field + 1),* }))
+                })
+            }
+
+            /// Returns half of the rectangle's size, rounded down, assuming it is ordered.
+            pub fn half_extents(&self) -> $vec<$t> {
+                (self.1 - self.0) / 2
+            }
+
+            /// Moves each edge of the rectangle inward by `amount`, saturating each component at
+            /// the numeric bounds.
+            pub fn saturating_deflated(self, amount: $t) -> $rect<$t> {
+                let v = $vec { $($field: amount),* };
+                $rect(self.0.saturating_add(v), self.1.saturating_sub(v))
+            }
+
+            /// Moves each edge of the rectangle outward by `amount`, saturating each component at
+            /// the numeric bounds.
+            pub fn saturating_inflated(self, amount: $t) -> $rect<$t> {
+                let v = $vec { $($field: amount),* };
+                $rect(self.0.saturating_sub(v), self.1.saturating_add(v))
+            }
+
+            /// Moves the rectangle by `offset`, saturating each component at the numeric bounds.
+            /// Unlike [translated](Self::translated), this may change the rectangle's size.
+            pub fn saturating_translated(self, offset: $vec<$t>) -> $rect<$t> {
+                $rect(self.0.saturating_add(offset), self.1.saturating_add(offset))
+            }
+
+            /// Returns the size of the rectangle converted to the type `U`, which is typically
+            /// `usize` for indexing buffers. Returns `None` if the rectangle is not ordered or if
+            /// a component of the size is out of range for `U`. Unlike [size](Self::size), this
+            /// cannot overflow, since sizes are computed in the unsigned counterpart of the
+            /// component type.
+            pub fn size_unsigned<U>(&self) -> Option<$vec<U>>
+            where $u: TryInto<U>
+            {
+                if !self.is_ordered() {
+                    return None;
+                }
+                Some($vec { $($field: self.1.$field.abs_diff(self.0.$field).try_into().ok()?),* })
+            }
+
+            /// Snaps `self.0` down and `self.1` up to multiples of `step`, producing the smallest
+            /// rectangle aligned to the grid that contains `self`, assuming `self` is ordered.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any component of `step` is zero. The snapped value of a component near
+            /// the numeric bounds may not be representable and will panic in debug mode.
+            pub fn snap_to_grid(self, step: $vec<$t>) -> $rect<$t> {
+                $rect(self.0.snap_to(step, RoundingMode::Floor),
+                      self.1.snap_to(step, RoundingMode::Ceil))
+            }
+
+            /// Snaps `self.0` up and `self.1` down to multiples of `step`, producing the largest
+            /// rectangle aligned to the grid that is contained by `self`, assuming `self` is
+            /// ordered. The result may not be ordered if `self` does not contain a grid cell.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any component of `step` is zero. The snapped value of a component near
+            /// the numeric bounds may not be representable and will panic in debug mode.
+            pub fn snap_to_grid_in(self, step: $vec<$t>) -> $rect<$t> {
+                $rect(self.0.snap_to(step, RoundingMode::Ceil),
+                      self.1.snap_to(step, RoundingMode::Floor))
+            }
+        }
+
+        impl $vec<$t> {
+            /// Wraps each component around the half-open range covered by `bounds`, as in a world
+            /// where leaving one edge re-enters at the opposite edge. This never overflows.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `bounds` is not positive.
+            pub fn wrap_into(self, bounds: &$rect<$t>) -> $vec<$t> {
+                assert!(bounds.is_positive());
+                let $rect(min, max) = *bounds;
+                $vec { $($field: {
+                    let d = self.$field.abs_diff(min.$field) % max.$field.abs_diff(min.$field);
+                    if self.$field >= min.$field {
+                        min.$field.wrapping_add(d as $t)
+                    } else if d == 0 {
+                        min.$field
+                    } else {
+                        max.$field.wrapping_sub(d as $t)
+                    }
+                }),* }
+            }
+        }
+    )* };
+}
+
+impl_int! {
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize,
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_rect_measure() {
+    assert_eq!(Rect2::new(1i32, 2, 4, 6).area(), 12);
+    assert_eq!(Rect2::new(1i32, 2, 4, 6).perimeter(), 14);
+    assert_eq!(Rect3::new(0u8, 0, 0, 2, 3, 4).volume(), 24);
+    assert_eq!(Rect3::new(0u8, 0, 0, 2, 3, 4).surface_area(), 52);
+    assert_eq!(Rect2::new(4i32, 2, 1, 6).checked_area(), None);
+    assert_eq!(Rect2::new(0u8, 0, 16, 16).checked_area(), None);
+    assert_eq!(Rect2::new(-100i8, 0, 100, 1).checked_perimeter(), None);
+    assert_eq!(Rect3::new(0u16, 0, 0, 2, 3, 4).checked_surface_area(), Some(52));
+    assert_eq!(Rect3::new(0u16, 0, 0, 2, 3, 4).checked_volume(), Some(24));
+}
+
+#[test]
+fn test_rect_checked_size() {
+    let r = Rect2::new(i32::MIN, 0, i32::MAX, 10);
+    assert_eq!(r.checked_width(), None);
+    assert_eq!(r.checked_height(), Some(10));
+    assert_eq!(r.checked_size(), None);
+    assert_eq!(Rect2::new(0u8, 5, 3, 4).checked_height(), None);
+    assert_eq!(Rect3::new(0i8, 0, -100, 1, 2, 100).checked_depth(), None);
+    assert_eq!(Rect3::new(0i8, 0, 0, 1, 2, 3).checked_size(), Some(Vector3::new(1, 2, 3)));
+}
+
+#[test]
+fn test_rect_inflate_overflow() {
+    let r = Rect2::new(1u8, 1, 254, 254);
+    assert_eq!(r.checked_inflated(1), Some(Rect2::new(0, 0, 255, 255)));
+    assert_eq!(r.checked_inflated(2), None);
+    assert_eq!(r.saturating_inflated(2), Rect2::new(0, 0, 255, 255));
+    assert_eq!(r.checked_deflated(2), Some(Rect2::new(3, 3, 252, 252)));
+    assert_eq!(r.saturating_deflated(200), Rect2::new(201, 201, 54, 54));
+    assert_eq!(r.checked_translated(Vector2::new(2, 0)), None);
+    assert_eq!(r.saturating_translated(Vector2::new(2, 0)), Rect2::new(3, 1, 255, 254));
+}
+
+#[test]
+fn test_rect_grid_cells() {
+    let cells = Rect2::new(0i32, 0, 10, 4).grid_cells(Vector2::new(3, 2));
+    assert!(cells.eq([
+        Rect2::new(0, 0, 3, 2), Rect2::new(3, 0, 6, 2), Rect2::new(6, 0, 10, 2),
+        Rect2::new(0, 2, 3, 4), Rect2::new(3, 2, 6, 4), Rect2::new(6, 2, 10, 4),
+    ]));
+    let cells = Rect2::new(-128i8, 0, 127, 1).grid_cells(Vector2::new(2, 1));
+    assert!(cells.eq([Rect2::new(-128, 0, -1, 1), Rect2::new(-1, 0, 127, 1)]));
+    assert_eq!(Rect3::new(0u8, 0, 0, 4, 4, 4).grid_cells(Vector3::new(2, 2, 2)).count(), 8);
+    assert_eq!(Rect2::new(0u8, 0, 4, 4).grid_cells(Vector2::new(0, 2)).count(), 0);
+    assert_eq!(Rect2::new(0u8, 0, 4, 4).grid_cells(Vector2::new(usize::MAX, 2)).count(), 0);
+}
+
+#[test]
+fn test_rect_border_points() {
+    let points = Rect2::new(0i32, 0, 3, 3).border_points();
+    assert!(points.eq([(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (1, 2), (0, 2), (0, 1)]
+                          .map(Vector2::from)));
+    let points = Rect2::new(5u8, 0, 6, 3).border_points();
+    assert!(points.eq([(5, 0), (5, 1), (5, 2)].map(Vector2::from)));
+    let points = Rect2::new(0u8, 7, 3, 8).border_points();
+    assert!(points.eq([(0, 7), (1, 7), (2, 7)].map(Vector2::from)));
+    assert_eq!(Rect2::new(i8::MIN, i8::MIN, i8::MAX, i8::MAX).border_points().count(), 1016);
+    assert_eq!(Rect2::new(3i32, 3, 3, 5).border_points().count(), 0);
+}
+
+#[test]
+fn test_rect_shell_points() {
+    assert_eq!(Rect3::new(0i32, 0, 0, 4, 5, 6).shell_points().count(), 4 * 5 * 6 - 2 * 3 * 4);
+    assert_eq!(Rect3::new(0u8, 0, 0, 1, 2, 3).shell_points().count(), 6);
+    assert_eq!(Rect3::new(0u8, 0, 0, 0, 2, 3).shell_points().count(), 0);
+    let mut points = Rect3::new(-1i8, -1, -1, 2, 2, 2).shell_points();
+    assert!(points.all(|p| p != Vector3::new(0, 0, 0)));
+    let points = Rect3::new(0u8, 0, 0, 3, 3, 3).shell_points().skip(9).take(8);
+    assert!(points.eq([(0, 0), (1, 0), (2, 0), (0, 1), (2, 1), (0, 2), (1, 2), (2, 2)]
+                          .map(|(x, y)| Vector3::new(x, y, 1))));
+}
+
+#[test]
+fn test_rect_rows() {
+    assert!(Rect2::new(2u8, 5, 4, 7).rows().eq([(5, 2..4), (6, 2..4)]));
+    assert_eq!(Rect2::new(2i32, 5, 2, 7).rows().count(), 0);
+    assert_eq!(Rect2::new(2i32, 7, 4, 5).rows().count(), 0);
+}
+
+#[test]
+fn test_rect_size_unsigned() {
+    let r = Rect2::new(i8::MIN, -3, i8::MAX, 4);
+    assert_eq!(r.size_unsigned::<usize>(), Some(Vector2::new(255, 7)));
+    assert_eq!(r.size_unsigned::<i8>(), None);
+    assert_eq!(Rect3::new(0u64, 5, 0, 1, 4, 1).size_unsigned::<usize>(), None);
+}
+
+#[test]
+fn test_rect_center() {
+    assert_eq!(Rect2::new(0i32, -3, 10, 0).center(), Vector2::new(5, -2));
+    assert_eq!(Rect2::new(i8::MIN, 100, i8::MAX, 120).center(), Vector2::new(-1, 110));
+    assert_eq!(Rect2::new(250u8, 0, 255, 0).center(), Vector2::new(252, 0));
+}
@@ -6,9 +6,47 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+/// Implements `aligned_within` for rectangles with integer or floating point components, which
+/// only differ in how `center` and `from_center_size` round. Defined ahead of the submodules so
+/// that they can use it.
+macro_rules! impl_aligned_within {
+    { $(#[$attr:meta])* $rect:ident<$t:ident>: $vec:ident($($field:ident),*) } => {
+        $(#[$attr])*
+        pub fn aligned_within(&self, container: &$rect<$t>, align: impl Into<$vec<Align>>)
+            -> $rect<$t>
+        {
+            let (align, size) = (align.into(), self.1 - self.0);
+            let centered = $rect::<$t>::from_center_size(container.center(), size);
+            let min = $vec { $($field: match align.$field {
+                Align::Start => container.0.$field,
+                Align::Center => centered.0.$field,
+                Align::End => container.1.$field - size.$field,
+            }),* };
+            $rect(min, min + size)
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+mod batch;
+
 #[cfg(feature = "ext-ops")]
 mod ext_ops;
 
+mod float;
+mod int;
+
+#[cfg(feature = "num-traits")]
+mod num_traits;
+
+#[cfg(feature = "serde")]
+mod repr;
+
+#[cfg(feature = "serde")]
+pub use repr::{RectMinMax, RectOriginSize};
+
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
 use core::ops::{
     Add,
     AddAssign,
@@ -17,10 +55,19 @@ use core::ops::{
     Mul,
     MulAssign,
     Neg,
+    Range,
+    Rem,
+    RemAssign,
     Sub,
     SubAssign,
 };
+use core::str::FromStr;
 
+use crate::axis::{Axis2, Axis3};
+use crate::convert::{SaturatingFrom, WrappingFrom};
+use crate::ops::{BoundingRect, ClosestPoint, Contains, Dot, Lerp, Norm};
+use crate::quad::Quad2;
+use crate::segment::Segment2;
 use crate::vec::{Vector2, Vector3};
 
 /// 2-dimensional, axis-aligned rectangle structure defined as two opposite points.
@@ -33,6 +80,160 @@ pub struct Rect2<T>(pub Vector2<T>, pub Vector2<T>);
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Rect3<T>(pub Vector3<T>, pub Vector3<T>);
 
+/// Classification of a rectangle by how its two points relate along each axis, as returned by
+/// `classify`. Each axis-carrying variant names the first axis to which it applies.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RectKind<A> {
+    /// `self.1` is greater than `self.0` along every axis, so the rectangle is non-empty.
+    Positive,
+    /// The rectangle is ordered but has zero extent along the given axis.
+    Flat(A),
+    /// `self.1` is less than `self.0` along the given axis.
+    Inverted(A),
+    /// Some pair of components cannot be compared, as with NaN.
+    Incomparable,
+}
+
+/// Error returned when parsing a rectangle from a string fails.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseRectError<E> {
+    /// The string is not of the form `[(x0, y0), (x1, y1)]` with one component per axis.
+    Syntax,
+    /// A component could not be parsed.
+    Scalar(E),
+}
+
+impl<E: Display> Display for ParseRectError<E> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        match self {
+            Self::Syntax => f.write_str("invalid rectangle syntax"),
+            Self::Scalar(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for ParseRectError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Syntax => None,
+            Self::Scalar(e) => Some(e),
+        }
+    }
+}
+
+impl<T> Rect2<T> {
+    /// Returns the area of the rectangle, assuming it is ordered.
+    pub fn area(&self) -> T
+    where for<'a> &'a T: Sub<Output = T>,
+          T: Mul<Output = T>
+    {
+        self.width() * self.height()
+    }
+
+    /// Returns the four edges of the rectangle, starting with the edge from `self.0` toward
+    /// increasing X and continuing around the rectangle so that each edge begins where the
+    /// previous one ends.
+    pub fn edges(&self) -> [Segment2<T>; 4]
+    where T: Clone
+    {
+        let Rect2(a, c) = self.clone();
+        let b = Vector2::new(c.x.clone(), a.y.clone());
+        let d = Vector2::new(a.x.clone(), c.y.clone());
+        [
+            Segment2(a.clone(), b.clone()),
+            Segment2(b, c.clone()),
+            Segment2(c, d.clone()),
+            Segment2(d, a),
+        ]
+    }
+
+    /// Extends the rectangle into a prism spanning `range` along `axis`. The rectangle's X and Y
+    /// components fill the two remaining axes in order, which undoes [Rect3::slice].
+    pub fn extrude(self, axis: Axis3, range: Range<T>) -> Rect3<T> {
+        let Rect2(a, b) = self;
+        match axis {
+            Axis3::X => Rect3::new(range.start, a.x, a.y, range.end, b.x, b.y),
+            Axis3::Y => Rect3::new(a.x, range.start, a.y, b.x, range.end, b.y),
+            Axis3::Z => Rect3::new(a.x, a.y, range.start, b.x, b.y, range.end),
+        }
+    }
+
+    /// Splits the rectangle into the nine patches of a nine-slice (nine-patch) layout, assuming
+    /// the rectangle is ordered and the insets fit within it. `insets.0` holds the widths of the
+    /// borders along `self.0.x` and `self.0.y`, and `insets.1` those along `self.1.x` and
+    /// `self.1.y`. The patches are ordered by X, then by Y, so that
+    /// `nine_slice(insets)[anchor as usize]` is the patch at that [Anchor](crate::Anchor).
+    pub fn nine_slice(&self, insets: Rect2<T>) -> [Rect2<T>; 9]
+    where T: Add<Output = T> + Clone + Sub<Output = T>
+    {
+        let Rect2(a, b) = self.clone();
+        let xs = [a.x.clone(), a.x + insets.0.x, b.x.clone() - insets.1.x, b.x];
+        let ys = [a.y.clone(), a.y + insets.0.y, b.y.clone() - insets.1.y, b.y];
+        core::array::from_fn(|i| {
+            let (x, y) = (i % 3, i / 3);
+            Rect2::new(xs[x].clone(), ys[y].clone(), xs[x + 1].clone(), ys[y + 1].clone())
+        })
+    }
+
+    /// Returns the area of the intersection of two positive rectangles, or `T::default()` if they
+    /// do not intersect. This is the numerator of the intersection-over-union metric.
+    pub fn overlap_area(&self, rhs: &Rect2<T>) -> T
+    where for<'a> &'a T: Sub<Output = T>,
+          T: Clone + Default + Mul<Output = T> + PartialOrd
+    {
+        self.clone().intersect(rhs.clone()).map_or_else(T::default, |r| r.area())
+    }
+
+    /// Returns the perimeter of the rectangle, assuming it is ordered.
+    pub fn perimeter(&self) -> T
+    where for<'a> &'a T: Sub<Output = T>,
+          T: Add<Output = T> + Clone
+    {
+        let half = self.width() + self.height();
+        half.clone() + half
+    }
+
+    /// Splits the rectangle into four quadrants which meet at `point`, which is assumed to lie
+    /// within the rectangle. The quadrants are ordered by X, then by Y, so index 0 is nearest to
+    /// `self.0` and index 3 is nearest to `self.1`.
+    pub fn quadrants_around(&self, point: Vector2<T>) -> [Rect2<T>; 4]
+    where T: Clone
+    {
+        self.split_around(point)
+    }
+
+    /// Splits a strip of the given width off the left edge (`self.0.x`), returning the strip and
+    /// the remainder. The rectangle is assumed to be ordered; see [split_at_x](Self::split_at_x).
+    pub fn split_left(self, width: T) -> (Rect2<T>, Rect2<T>)
+    where T: Add<Output = T> + Clone + PartialOrd
+    {
+        let x = self.0.x.clone() + width;
+        self.split_at_x(x)
+    }
+
+    /// Splits a strip of the given height off the top edge (`self.0.y`, as in the Y-down
+    /// coordinates used by most UI and image APIs), returning the strip and the remainder. The
+    /// rectangle is assumed to be ordered; see [split_at_y](Self::split_at_y).
+    pub fn split_top(self, height: T) -> (Rect2<T>, Rect2<T>)
+    where T: Add<Output = T> + Clone + PartialOrd
+    {
+        let y = self.0.y.clone() + height;
+        self.split_at_y(y)
+    }
+
+    /// Returns the corners of the rectangle as a [Quad2], in the same order as the starting
+    /// points of [edges](Self::edges), so that it can be transformed beyond axis alignment.
+    pub fn to_quad(&self) -> Quad2<T>
+    where T: Clone
+    {
+        let Rect2(a, c) = self.clone();
+        let b = Vector2::new(c.x.clone(), a.y.clone());
+        let d = Vector2::new(a.x.clone(), c.y.clone());
+        Quad2(a, b, c, d)
+    }
+}
+
 impl<T> Rect3<T> {
     /// Returns `&self.1.z - &self.0.z`.
     pub fn depth<'a>(&'a self) -> <&'a T as Sub>::Output
@@ -40,6 +241,72 @@ impl<T> Rect3<T> {
     {
         &self.1.z - &self.0.z
     }
+
+    /// Returns the six faces of the prism as flat prisms, in the same order as the variants of
+    /// [Direction6](crate::Direction6), so that `faces()[dir as usize]` is the face on the side
+    /// that `dir` points toward.
+    pub fn faces(&self) -> [Rect3<T>; 6]
+    where T: Clone
+    {
+        let Rect3(a, b) = self;
+        let v = |x: &T, y: &T, z: &T| Vector3::new(x.clone(), y.clone(), z.clone());
+        [
+            Rect3(v(&b.x, &a.y, &a.z), b.clone()),
+            Rect3(a.clone(), v(&a.x, &b.y, &b.z)),
+            Rect3(v(&a.x, &b.y, &a.z), b.clone()),
+            Rect3(a.clone(), v(&b.x, &a.y, &b.z)),
+            Rect3(v(&a.x, &a.y, &b.z), b.clone()),
+            Rect3(a.clone(), v(&b.x, &b.y, &a.z)),
+        ]
+    }
+
+    /// Splits the prism into eight octants which meet at `point`, which is assumed to lie within
+    /// the prism. The octants are ordered by X, then by Y, then by Z, so index 0 is nearest to
+    /// `self.0` and index 7 is nearest to `self.1`.
+    pub fn octants_around(&self, point: Vector3<T>) -> [Rect3<T>; 8]
+    where T: Clone
+    {
+        self.split_around(point)
+    }
+
+    /// Returns the volume of the intersection of two positive prisms, or `T::default()` if they do
+    /// not intersect.
+    pub fn overlap_volume(&self, rhs: &Rect3<T>) -> T
+    where for<'a> &'a T: Sub<Output = T>,
+          T: Clone + Default + Mul<Output = T> + PartialOrd
+    {
+        self.clone().intersect(rhs.clone()).map_or_else(T::default, |r| r.volume())
+    }
+
+    /// Projects the prism onto the plane perpendicular to `axis` by dropping that axis, keeping
+    /// the two remaining axes in order. For example, slicing along [Axis3::Y] yields the X/Z
+    /// footprint.
+    pub fn slice(self, axis: Axis3) -> Rect2<T> {
+        let Rect3(a, b) = self;
+        match axis {
+            Axis3::X => Rect2::new(a.y, a.z, b.y, b.z),
+            Axis3::Y => Rect2::new(a.x, a.z, b.x, b.z),
+            Axis3::Z => Rect2::new(a.x, a.y, b.x, b.y),
+        }
+    }
+
+    /// Returns the surface area of the prism, assuming it is ordered.
+    pub fn surface_area(&self) -> T
+    where for<'a> &'a T: Sub<Output = T>,
+          T: Add<Output = T> + Clone + Mul<Output = T>
+    {
+        let (w, h, d) = (self.width(), self.height(), self.depth());
+        let half = w.clone() * h.clone() + h * d.clone() + d * w;
+        half.clone() + half
+    }
+
+    /// Returns the volume of the prism, assuming it is ordered.
+    pub fn volume(&self) -> T
+    where for<'a> &'a T: Sub<Output = T>,
+          T: Mul<Output = T>
+    {
+        self.width() * self.height() * self.depth()
+    }
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -73,23 +340,23 @@ macro_rules! impl_unary_ops {
 macro_rules! impl_scalar_ops {
     { $(impl $trait:ident::$fn:ident for $rect:ident;)* } => { $(
         impl<T> $trait<T> for $rect<T>
-        where T: Copy + $trait
+        where T: Clone + $trait
         {
             type Output = $rect<<T as $trait>::Output>;
 
             fn $fn(self, rhs: T) -> Self::Output {
-                $rect($trait::$fn(self.0, rhs), $trait::$fn(self.1, rhs))
+                $rect($trait::$fn(self.0, rhs.clone()), $trait::$fn(self.1, rhs.clone()))
             }
         }
 
         impl<'a, T> $trait<T> for &'a $rect<T>
-        where T: Copy,
+        where T: Clone,
               &'a T: $trait<T>
         {
             type Output = $rect<<&'a T as $trait<T>>::Output>;
 
             fn $fn(self, rhs: T) -> Self::Output {
-                $rect($trait::$fn(&self.0, rhs), $trait::$fn(&self.1, rhs))
+                $rect($trait::$fn(&self.0, rhs.clone()), $trait::$fn(&self.1, rhs.clone()))
             }
         }
 
@@ -119,11 +386,11 @@ macro_rules! impl_scalar_ops {
 macro_rules! impl_scalar_assign_ops {
     { $(impl $trait:ident::$fn:ident for $rect:ident;)* } => { $(
         impl<T> $trait<T> for $rect<T>
-        where T: Copy + $trait
+        where T: Clone + $trait
         {
             fn $fn(&mut self, rhs: T) {
-                $trait::$fn(&mut self.0, rhs);
-                $trait::$fn(&mut self.1, rhs);
+                $trait::$fn(&mut self.0, rhs.clone());
+                $trait::$fn(&mut self.1, rhs.clone());
             }
         }
 
@@ -142,23 +409,23 @@ macro_rules! impl_scalar_assign_ops {
 macro_rules! impl_vec_ops {
     { $(impl $trait:ident::$fn:ident for $rect:ident: $vec:ident;)* } => { $(
         impl<T> $trait<$vec<T>> for $rect<T>
-        where T: Copy + $trait
+        where T: Clone + $trait
         {
             type Output = $rect<<T as $trait>::Output>;
 
             fn $fn(self, rhs: $vec<T>) -> Self::Output {
-                $rect($trait::$fn(self.0, rhs), $trait::$fn(self.1, rhs))
+                $rect($trait::$fn(self.0, rhs.clone()), $trait::$fn(self.1, rhs.clone()))
             }
         }
 
         impl<'a, T> $trait<$vec<T>> for &'a $rect<T>
-        where T: Copy,
+        where T: Clone,
               &'a T: $trait<T>
         {
             type Output = $rect<<&'a T as $trait<T>>::Output>;
 
             fn $fn(self, rhs: $vec<T>) -> Self::Output {
-                $rect($trait::$fn(&self.0, rhs), $trait::$fn(&self.1, rhs))
+                $rect($trait::$fn(&self.0, rhs.clone()), $trait::$fn(&self.1, rhs.clone()))
             }
         }
 
@@ -188,11 +455,11 @@ macro_rules! impl_vec_ops {
 macro_rules! impl_vec_assign_ops {
     { $(impl $trait:ident::$fn:ident for $rect:ident: $vec:ident;)* } => { $(
         impl<T> $trait<$vec<T>> for $rect<T>
-        where T: Copy + $trait
+        where T: Clone + $trait
         {
             fn $fn(&mut self, rhs: $vec<T>) {
-                $trait::$fn(&mut self.0, rhs);
-                $trait::$fn(&mut self.1, rhs);
+                $trait::$fn(&mut self.0, rhs.clone());
+                $trait::$fn(&mut self.1, rhs.clone());
             }
         }
 
@@ -216,6 +483,83 @@ macro_rules! impl_all {
             ($($field0:ident: $t0:ident),* | $($field1:ident: $t1:ident),*);
         )* } => { $(
         impl<T> $rect<T> {
+            /// Restricts both points of the rectangle to the area covered by `bounds`, which is
+            /// assumed to be ordered.
+            pub fn clamp(self, bounds: $rect<T>) -> $rect<T>
+            where T: Clone + PartialOrd
+            {
+                let min = bounds.0.clone();
+                let max = bounds.1.clone();
+                $rect(self.0.clamp(min, max), self.1.clamp(bounds.0, bounds.1))
+            }
+
+            /// Returns the point within the rectangle, which is assumed to be ordered, that is
+            /// nearest to `point`. Points already inside the rectangle are returned unchanged.
+            pub fn clamp_point(&self, point: $vec<T>) -> $vec<T>
+            where T: Clone + PartialOrd
+            {
+                point.clamp(self.0.clone(), self.1.clone())
+            }
+
+            /// Intersects each rectangle in `rects` with `self` in place, moving the non-empty
+            /// results to the front of the slice in their original order, and returns how many
+            /// there are. The remaining elements are left in an unspecified state. This is
+            /// equivalent to filtering `rects` through [intersect](Self::intersect), but avoids
+            /// allocating or constructing an `Option` per element.
+            pub fn clip_all(&self, rects: &mut [$rect<T>]) -> usize
+            where T: Clone + PartialOrd
+            {
+                if !self.is_partially_positive() {
+                    return 0;
+                }
+
+                let mut len = 0;
+                for i in 0..rects.len() {
+                    let r = &rects[i];
+                    let clipped = $rect(r.0.clone().max(self.0.clone()),
+                                        r.1.clone().min(self.1.clone()));
+                    if clipped.is_partially_positive() {
+                        rects[len] = clipped;
+                        len += 1;
+                    }
+                }
+                len
+            }
+
+            /// Returns `true` if `point` lies within the half-open range `[self.0, self.1)` on
+            /// every axis, as is conventional for grid cells and pixels: points on the minimum
+            /// edges are contained, but points on the maximum edges are not. This is also the
+            /// policy of the [Contains] impl.
+            pub fn contains_point(&self, point: &$vec<T>) -> bool
+            where T: PartialOrd
+            {
+                true $(&& self.0.$field <= point.$field && point.$field < self.1.$field)*
+            }
+
+            /// Returns `true` if `point` lies strictly inside the rectangle, excluding all edges.
+            pub fn contains_point_exclusive(&self, point: &$vec<T>) -> bool
+            where T: PartialOrd
+            {
+                true $(&& self.0.$field < point.$field && point.$field < self.1.$field)*
+            }
+
+            /// Returns `true` if `point` lies inside the rectangle or on any of its edges.
+            pub fn contains_point_inclusive(&self, point: &$vec<T>) -> bool
+            where T: PartialOrd
+            {
+                true $(&& self.0.$field <= point.$field && point.$field <= self.1.$field)*
+            }
+
+            /// Returns `true` if `rhs` lies entirely within `self`, including the case where they
+            /// share edges. Returns `false` if either rectangle is not positive.
+            pub fn contains_rect(&self, rhs: &$rect<T>) -> bool
+            where T: PartialOrd
+            {
+                self.is_partially_positive()
+                    && rhs.is_partially_positive()
+                    $(&& self.0.$field <= rhs.0.$field && rhs.1.$field <= self.1.$field)*
+            }
+
             /// Converts the rectangle's scalar components to another type.
             pub fn convert<U>(self) -> $rect<U>
             where T: Into<U>
@@ -223,6 +567,66 @@ macro_rules! impl_all {
                 $rect(self.0.convert(), self.1.convert())
             }
 
+            /// Moves each edge of the rectangle inward by `amount`, assuming it is ordered. The
+            /// result may not be ordered if `amount` exceeds half of the rectangle's size.
+            pub fn deflated(self, amount: T) -> $rect<T>
+            where T: Add<Output = T> + Clone + Sub<Output = T>
+            {
+                let v = $vec { $($field: amount.clone()),* };
+                $rect(self.0 + v.clone(), self.1 - v)
+            }
+
+            /// Returns up to two non-overlapping rectangles per axis which together cover the part
+            /// of `self` not covered by `rhs`. Both rectangles are assumed to be ordered. If they
+            /// do not intersect, `self` is returned unchanged, unless it is not positive.
+            pub fn difference(&self, rhs: &$rect<T>) -> impl Iterator<Item = $rect<T>>
+            where T: Clone + PartialOrd
+            {
+                let intersects = self.intersects(rhs);
+                let mut rest = self.clone();
+                let pieces = [$({
+                    let (mut below, mut above) = (None, None);
+                    if intersects && rhs.0.$field > rest.0.$field {
+                        let mut piece = rest.clone();
+                        piece.1.$field = rhs.0.$field.clone();
+                        rest.0.$field = rhs.0.$field.clone();
+                        below = Some(piece);
+                    }
+                    if intersects && rhs.1.$field < rest.1.$field {
+                        let mut piece = rest.clone();
+                        piece.0.$field = rhs.1.$field.clone();
+                        rest.1.$field = rhs.1.$field.clone();
+                        above = Some(piece);
+                    }
+                    [below, above]
+                }),*];
+                let whole = (!intersects && self.is_partially_positive()).then(|| self.clone());
+                whole.into_iter().chain(pieces.into_iter().flatten().flatten())
+            }
+
+            /// Returns the squared Euclidean distance from `point` to the nearest point covered by
+            /// the rectangle, which is zero if `point` is inside the rectangle. The rectangle is
+            /// assumed to be ordered.
+            pub fn distance_squared_to_point(&self, point: $vec<T>) -> T
+            where T: Add<Output = T> + Clone + Mul<Output = T> + PartialOrd + Sub<Output = T>
+            {
+                let nearest = point.clone().clamp(self.0.clone(), self.1.clone());
+                let d = point.clone().max(nearest.clone()) - point.min(nearest);
+                d.clone().dot(d)
+            }
+
+            /// Returns the distance from `point` to the nearest point covered by the rectangle
+            /// under `metric`, which is zero if `point` is inside the rectangle. The rectangle is
+            /// assumed to be ordered.
+            pub fn distance_to_point<M>(&self, point: $vec<T>, metric: M)
+                -> <$vec<T> as Norm<M>>::Output
+            where T: Clone + PartialOrd + Sub<Output = T>,
+                  $vec<T>: Norm<M>
+            {
+                let nearest = point.clone().clamp(self.0.clone(), self.1.clone());
+                (point.clone().max(nearest.clone()) - point.min(nearest)).norm(metric)
+            }
+
             /// Returns the smallest possible rectangle that includes `self` and `rhs`, assuming
             /// that both rectangles are positive. Simply returns `self` if either rectangle is not
             /// positive.
@@ -233,9 +637,35 @@ macro_rules! impl_all {
                     return self;
                 }
 
-                $rect(
-                    $vec { $($field: partial_min(self.0.$field, rhs.0.$field)),* },
-                    $vec { $($field: partial_max(self.1.$field, rhs.1.$field)),* })
+                $rect(self.0.min(rhs.0), self.1.max(rhs.1))
+            }
+
+            /// Constructs a rectangle extending `half_extents` in each direction from `center`.
+            pub fn from_center_half_extents(center: $vec<T>, half_extents: $vec<T>) -> $rect<T>
+            where T: Add<Output = T> + Clone + Sub<Output = T>
+            {
+                $rect(center.clone() - half_extents.clone(), center + half_extents)
+            }
+
+            /// Constructs a rectangle whose minimum point is `origin` and whose size is `size`.
+            pub fn from_origin_size(origin: $vec<T>, size: $vec<T>) -> $rect<T>
+            where T: Add<Output = T> + Clone
+            {
+                $rect(origin.clone(), origin + size)
+            }
+
+            /// Returns the smallest ordered rectangle that contains every point in `points`, or
+            /// `None` if `points` is empty. If a pair of components is not comparable, the
+            /// component already in the rectangle is kept.
+            pub fn from_points<I>(points: I) -> Option<$rect<T>>
+            where I: IntoIterator<Item = $vec<T>>,
+                  T: Clone + PartialOrd
+            {
+                let mut points = points.into_iter();
+                let first = points.next()?;
+                Some(points.fold($rect(first.clone(), first), |r, p| {
+                    $rect(r.0.min(p.clone()), r.1.max(p))
+                }))
             }
 
             /// Returns `&self.1.y - &self.0.y`.
@@ -245,6 +675,14 @@ macro_rules! impl_all {
                 &self.1.y - &self.0.y
             }
 
+            /// Moves each edge of the rectangle outward by `amount`, assuming it is ordered.
+            pub fn inflated(self, amount: T) -> $rect<T>
+            where T: Add<Output = T> + Clone + Sub<Output = T>
+            {
+                let v = $vec { $($field: amount.clone()),* };
+                $rect(self.0 - v.clone(), self.1 + v)
+            }
+
             /// Returns the intersection of two positive rectangles. Returns `None` if either
             /// rectangle's points are not ordered or if the rectangles do not intersect.
             pub fn intersect(self, rhs: $rect<T>) -> Option<$rect<T>>
@@ -254,9 +692,7 @@ macro_rules! impl_all {
                     return None;
                 }
 
-                let intersection = $rect(
-                    $vec { $($field: partial_max(self.0.$field, rhs.0.$field)),* },
-                    $vec { $($field: partial_min(self.1.$field, rhs.1.$field)),* });
+                let intersection = $rect(self.0.max(rhs.0), self.1.min(rhs.1));
 
                 if intersection.is_partially_positive() {
                     Some(intersection)
@@ -265,6 +701,27 @@ macro_rules! impl_all {
                 }
             }
 
+            /// Returns `true` if two positive rectangles overlap by a positive amount on every
+            /// axis. This is equivalent to `self.intersect(rhs).is_some()`, but does not construct
+            /// the intersection.
+            pub fn intersects(&self, rhs: &$rect<T>) -> bool
+            where T: PartialOrd
+            {
+                self.is_partially_positive()
+                    && rhs.is_partially_positive()
+                    $(&& self.0.$field < rhs.1.$field && rhs.0.$field < self.1.$field)*
+            }
+
+            /// Returns true if the rectangle covers no area or volume. This holds when it is flat
+            /// or inverted along any axis, or when its components cannot be compared, and is the
+            /// negation of [is_partially_positive](Self::is_partially_positive). Use
+            /// [classify](Self::classify) to find out why a rectangle is empty.
+            pub fn is_empty(&self) -> bool
+            where T: PartialOrd
+            {
+                !self.is_partially_positive()
+            }
+
             /// Returns true if each field in `self.1` is greater than or equal to the corresponding
             /// field in `self.0`.
             pub fn is_ordered(&self) -> bool
@@ -297,6 +754,17 @@ macro_rules! impl_all {
                 self.is_partially_positive()
             }
 
+            /// Maps `point` to normalized coordinates relative to the rectangle, where `self.0`
+            /// maps to zero and `self.1` maps to one on each axis. This is the inverse of
+            /// [point_at](Self::point_at). Components of `point` outside the rectangle map outside
+            /// the range `[0, 1]`, and an empty axis divides by zero.
+            pub fn normalized_of(&self, point: $vec<T>) -> $vec<T>
+            where T: Clone + Div<Output = T> + Sub<Output = T>
+            {
+                $vec { $($field: (point.$field - self.0.$field.clone())
+                                 / (self.1.$field.clone() - self.0.$field.clone())),* }
+            }
+
             /// Constructs a rectangle from decomposed vectors.
             pub const fn new($($field0: $t0),*, $($field1: $t1),*) -> $rect<T> {
                 $rect($vec::new($($field0),*), $vec::new($($field1),*))
@@ -309,6 +777,13 @@ macro_rules! impl_all {
                 self.partially_ordered()
             }
 
+            /// Returns the rectangle's minimum point, `self.0`, assuming it is ordered.
+            pub fn origin(&self) -> $vec<T>
+            where T: Clone
+            {
+                self.0.clone()
+            }
+
             /// Sorts the corresponding fields of `self.0` and `self.1` in ascending order.
             pub fn partially_ordered(self) -> $rect<T>
             where T: PartialOrd
@@ -317,6 +792,17 @@ macro_rules! impl_all {
                 $rect($vec::new($($field.0),*), $vec::new($($field.1),*))
             }
 
+            /// Maps normalized coordinates to an absolute point, where zero maps to `self.0` and
+            /// one maps to `self.1` on each axis. This is how texture coordinates are mapped into
+            /// a region of a texture atlas.
+            pub fn point_at(&self, normalized: $vec<T>) -> $vec<T>
+            where T: Add<Output = T> + Clone + Mul<Output = T> + Sub<Output = T>
+            {
+                $vec { $($field: self.0.$field.clone()
+                                 + (self.1.$field.clone() - self.0.$field.clone())
+                                   * normalized.$field),* }
+            }
+
             /// Converts the rectangle's scalar components to another type.
             pub fn ref_convert<'a, U>(&'a self) -> $rect<U>
             where &'a T: Into<U>
@@ -324,6 +810,31 @@ macro_rules! impl_all {
                 $rect(self.0.ref_convert(), self.1.ref_convert())
             }
 
+            /// Converts the rectangle's scalar components to another numeric type, clamping values
+            /// which are out of range to the bounds of the target type.
+            pub fn saturating_convert<U>(self) -> $rect<U>
+            where U: SaturatingFrom<T>
+            {
+                $rect(self.0.saturating_convert(), self.1.saturating_convert())
+            }
+
+            /// Scales the rectangle about `anchor` by `factor`, leaving `anchor` fixed. A negative
+            /// factor produces a rectangle whose points are not ordered.
+            pub fn scaled_around(self, anchor: $vec<T>, factor: T) -> $rect<T>
+            where T: Add<Output = T> + Clone + Mul<Output = T> + Sub<Output = T>
+            {
+                $rect(self.0.scaled_around(anchor.clone(), factor.clone()),
+                      self.1.scaled_around(anchor, factor))
+            }
+
+            /// Moves `self.1` so that the rectangle has the given size while keeping its
+            /// [origin](Self::origin) fixed.
+            pub fn set_size(&mut self, size: $vec<T>)
+            where T: Add<Output = T> + Clone
+            {
+                self.1 = self.0.clone() + size;
+            }
+
             /// Returns `self.1 - self.0`.
             pub fn size<'a>(&'a self) -> $vec<<&'a T as Sub>::Output>
             where &'a T: Sub
@@ -331,6 +842,35 @@ macro_rules! impl_all {
                 &self.1 - &self.0
             }
 
+            /// Splits the rectangle into `2^DIM` cells which meet at `point`. Bit `i` of a cell's
+            /// index is set if the cell lies above `point` on the `i`th axis.
+            fn split_around<const N: usize>(&self, point: $vec<T>) -> [$rect<T>; N]
+            where T: Clone
+            {
+                core::array::from_fn(|i| {
+                    let upper = $vec::from(core::array::from_fn(|axis| i >> axis & 1 != 0));
+                    $rect(
+                        $vec { $($field: if upper.$field {
+                            point.$field.clone()
+                        } else {
+                            self.0.$field.clone()
+                        }),* },
+                        $vec { $($field: if upper.$field {
+                            self.1.$field.clone()
+                        } else {
+                            point.$field.clone()
+                        }),* },
+                    )
+                })
+            }
+
+            /// Moves the rectangle by `offset`. This is equivalent to `self + offset`.
+            pub fn translated(self, offset: $vec<T>) -> $rect<T>
+            where T: Add<Output = T> + Clone
+            {
+                $rect(self.0 + offset.clone(), self.1 + offset)
+            }
+
             /// Attempts to convert the rectangle's scalar components to another type.
             pub fn try_convert<U>(self) -> Result<$rect<U>, <T as TryInto<U>>::Error>
             where T: TryInto<U>
@@ -346,12 +886,123 @@ macro_rules! impl_all {
                 Ok($rect(self.0.try_ref_convert()?, self.1.try_ref_convert()?))
             }
 
+            /// Returns the smallest rectangle that includes `self` and `rhs`. A rectangle which is
+            /// not positive is treated as empty, so it acts as the identity: the other rectangle
+            /// is returned unchanged. Unlike [expand](Self::expand), this makes it safe to fold
+            /// bounds over collections that may contain empty rectangles.
+            pub fn union(&self, rhs: &$rect<T>) -> $rect<T>
+            where T: Clone + PartialOrd
+            {
+                if !rhs.is_partially_positive() {
+                    return self.clone();
+                }
+                if !self.is_partially_positive() {
+                    return rhs.clone();
+                }
+                $rect(self.0.clone().min(rhs.0.clone()), self.1.clone().max(rhs.1.clone()))
+            }
+
             /// Returns `&self.1.x - &self.0.x`.
             pub fn width<'a>(&'a self) -> <&'a T as Sub>::Output
             where &'a T: Sub
             {
                 &self.1.x - &self.0.x
             }
+
+            /// Converts the rectangle's scalar components to another numeric type with the
+            /// semantics of an `as` cast.
+            pub fn wrapping_convert<U>(self) -> $rect<U>
+            where U: WrappingFrom<T>
+            {
+                $rect(self.0.wrapping_convert(), self.1.wrapping_convert())
+            }
+        }
+
+        impl<T> BoundingRect for $rect<T>
+        where T: Clone + PartialOrd
+        {
+            type Output = $rect<T>;
+
+            fn bounding_rect(&self) -> $rect<T> {
+                self.clone().partially_ordered()
+            }
+        }
+
+        /// Clamps the point to the rectangle, which is assumed to be ordered, as with
+        /// [clamp_point](Self::clamp_point).
+        impl<T> ClosestPoint<$vec<T>> for $rect<T>
+        where T: Clone + PartialOrd
+        {
+            fn closest_point(&self, point: &$vec<T>) -> $vec<T> {
+                self.clamp_point(point.clone())
+            }
+        }
+
+        /// Tests whether a point lies within the half-open range covered by the rectangle, as with
+        /// [contains_point](Self::contains_point).
+        impl<T> Contains<$vec<T>> for $rect<T>
+        where T: PartialOrd
+        {
+            fn contains(&self, rhs: &$vec<T>) -> bool {
+                self.contains_point(rhs)
+            }
+        }
+
+        /// Tests whether another rectangle lies within the rectangle, as with
+        /// [contains_rect](Self::contains_rect).
+        impl<T> Contains<$rect<T>> for $rect<T>
+        where T: PartialOrd
+        {
+            fn contains(&self, rhs: &$rect<T>) -> bool {
+                self.contains_rect(rhs)
+            }
+        }
+
+        /// Grows the rectangle, which is assumed to be ordered, just enough to include each
+        /// point. The rectangle is never treated as empty, so start from a rectangle that
+        /// covers a known point, such as one returned by `from_points`.
+        impl<T> Extend<$vec<T>> for $rect<T>
+        where T: Clone + PartialOrd
+        {
+            fn extend<I: IntoIterator<Item = $vec<T>>>(&mut self, iter: I) {
+                for point in iter {
+                    self.0 = self.0.clone().min(point.clone());
+                    self.1 = self.1.clone().max(point);
+                }
+            }
+        }
+
+        /// Grows the rectangle to include each rectangle as if by [union](Self::union), so
+        /// rectangles which are not positive are skipped.
+        impl<T> Extend<$rect<T>> for $rect<T>
+        where T: Clone + PartialOrd
+        {
+            fn extend<I: IntoIterator<Item = $rect<T>>>(&mut self, iter: I) {
+                for rect in iter {
+                    *self = self.union(&rect);
+                }
+            }
+        }
+
+        #[doc = concat!("Collects points into their bounding rectangle. See [",
+                        stringify!($rect), "::from_points].")]
+        impl<T> FromIterator<$vec<T>> for Option<$rect<T>>
+        where T: Clone + PartialOrd
+        {
+            fn from_iter<I: IntoIterator<Item = $vec<T>>>(iter: I) -> Option<$rect<T>> {
+                $rect::from_points(iter)
+            }
+        }
+
+        /// Interpolates both corners of the rectangle with the vector [Lerp] implementation, which
+        /// is useful for animating between two layouts.
+        impl<T, F> Lerp<F> for $rect<T>
+        where T: Lerp<F>,
+              F: Clone
+        {
+            fn lerp(self, rhs: $rect<T>, t: F) -> $rect<T> {
+                $rect(self.0.lerp(rhs.0, t.clone()), self.1.lerp(rhs.1, t))
+            }
         }
 
         impl<T> From<($($t0),*, $($t1),*)> for $rect<T> {
@@ -378,6 +1029,53 @@ macro_rules! impl_all {
             }
         }
 
+        /// Formats the rectangle as `[(x0, y0), (x1, y1)]`.
+        impl<T: Display> Display for $rect<T> {
+            fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+                f.write_str("[")?;
+                Display::fmt(&self.0, f)?;
+                f.write_str(", ")?;
+                Display::fmt(&self.1, f)?;
+                f.write_str("]")
+            }
+        }
+
+        /// Parses a rectangle in the format produced by [Display], ignoring whitespace around
+        /// each token.
+        impl<T: FromStr> FromStr for $rect<T> {
+            type Err = ParseRectError<T::Err>;
+
+            fn from_str(s: &str) -> Result<$rect<T>, ParseRectError<T::Err>> {
+                let (a, b) = split_rect_str(s).ok_or(ParseRectError::Syntax)?;
+                let point = |s: &str| -> Result<$vec<T>, ParseRectError<T::Err>> {
+                    let mut parts = s.split(',').map(str::trim);
+                    let point = $vec { $($field: parts.next()
+                                                      .ok_or(ParseRectError::Syntax)?
+                                                      .parse()
+                                                      .map_err(ParseRectError::Scalar)?),* };
+                    match parts.next() {
+                        None => Ok(point),
+                        Some(_) => Err(ParseRectError::Syntax),
+                    }
+                };
+                Ok($rect(point(a)?, point(b)?))
+            }
+        }
+
+        impl<T> From<($(Range<$t0>),*)> for $rect<T> {
+            fn from(ranges: ($(Range<$t0>),*)) -> $rect<T> {
+                let ($($field),*) = ranges;
+                $rect($vec { $($field: $field.start),* }, $vec { $($field: $field.end),* })
+            }
+        }
+
+        impl<T> From<$rect<T>> for ($(Range<$t0>),*) {
+            fn from(rect: $rect<T>) -> ($(Range<$t0>),*) {
+                let $rect(min, max) = rect;
+                ($(min.$field..max.$field),*)
+            }
+        }
+
         impl_unary_ops! {
             impl Neg::neg for $rect;
         }
@@ -385,17 +1083,20 @@ macro_rules! impl_all {
         impl_scalar_ops! {
             impl Div::div for $rect;
             impl Mul::mul for $rect;
+            impl Rem::rem for $rect;
         }
 
         impl_scalar_assign_ops! {
             impl DivAssign::div_assign for $rect;
             impl MulAssign::mul_assign for $rect;
+            impl RemAssign::rem_assign for $rect;
         }
 
         impl_vec_ops! {
             impl Add::add for $rect: $vec;
             impl Div::div for $rect: $vec;
             impl Mul::mul for $rect: $vec;
+            impl Rem::rem for $rect: $vec;
             impl Sub::sub for $rect: $vec;
         }
 
@@ -403,32 +1104,157 @@ macro_rules! impl_all {
             impl AddAssign::add_assign for $rect: $vec;
             impl DivAssign::div_assign for $rect: $vec;
             impl MulAssign::mul_assign for $rect: $vec;
+            impl RemAssign::rem_assign for $rect: $vec;
             impl SubAssign::sub_assign for $rect: $vec;
         }
     )* };
 }
 
+/// Implements functions relating rectangles to axes.
+macro_rules! impl_axis {
+    {
+        $(impl $rect:ident: $axis:ident($(
+            $field:ident: $variant:ident =>
+                ($flip:ident, $flip_about:ident, $range:ident, $split:ident)
+        ),*);)*
+    } => { $(
+        impl<T> $rect<T> {
+            /// Classifies the rectangle by comparing its two points along each axis. An inverted
+            /// axis takes precedence over a flat one, and components which cannot be compared
+            /// take precedence over both.
+            pub fn classify(&self) -> RectKind<$axis>
+            where T: PartialOrd
+            {
+                let mut kind = RectKind::Positive;
+                $(
+                    match self.0.$field.partial_cmp(&self.1.$field) {
+                        Some(Ordering::Less) => {},
+                        Some(Ordering::Equal) => if kind == RectKind::Positive {
+                            kind = RectKind::Flat($axis::$variant);
+                        },
+                        Some(Ordering::Greater) => if !matches!(kind, RectKind::Inverted(_)) {
+                            kind = RectKind::Inverted($axis::$variant);
+                        },
+                        None => return RectKind::Incomparable,
+                    }
+                )*
+                kind
+            }
+
+            $(
+                #[doc = concat!("Mirrors the rectangle across the plane `", stringify!($field),
+                                " = 0`. The `", stringify!($field), "` components of the two ",
+                                "points are swapped so that an ordered rectangle stays ordered.")]
+                pub fn $flip(self) -> $rect<T>
+                where T: Neg<Output = T>
+                {
+                    let $rect(mut min, mut max) = self;
+                    core::mem::swap(&mut min.$field, &mut max.$field);
+                    $rect(min.$flip(), max.$flip())
+                }
+
+                #[doc = concat!("Mirrors the rectangle across the plane `", stringify!($field),
+                                " = at`. The `", stringify!($field),
+                                "` components of the two points are swapped so that an ordered ",
+                                "rectangle stays ordered.")]
+                ///
+                /// # Panics
+                ///
+                /// The mirrored components are computed as `at + (at - c)`, so an intermediate
+                /// value which is not representable will panic in debug mode. This happens for
+                /// unsigned rectangles whenever `at` is less than the far edge, and for signed
+                /// rectangles near the numeric bounds.
+                pub fn $flip_about(self, at: T) -> $rect<T>
+                where T: Add<Output = T> + Clone + Sub<Output = T>
+                {
+                    let $rect(mut min, mut max) = self;
+                    let (lo, hi) = (min.$field.clone(), max.$field.clone());
+                    min.$field = at.clone() + (at.clone() - hi);
+                    max.$field = at.clone() + (at - lo);
+                    $rect(min, max)
+                }
+
+                #[doc = concat!("Returns `self.0.", stringify!($field), "..self.1.",
+                                stringify!($field), "`.")]
+                pub fn $range(&self) -> Range<T>
+                where T: Clone
+                {
+                    self.0.$field.clone()..self.1.$field.clone()
+                }
+
+                #[doc = concat!("Splits the rectangle, which is assumed to be ordered, into the ",
+                                "parts below and above `", stringify!($field), "`. The split ",
+                                "coordinate is clamped to the rectangle, so both parts are ",
+                                "ordered and one may be empty.")]
+                pub fn $split(self, $field: T) -> ($rect<T>, $rect<T>)
+                where T: Clone + PartialOrd
+                {
+                    let $rect(min, max) = self;
+                    let at = if $field < min.$field {
+                        min.$field.clone()
+                    } else if $field > max.$field {
+                        max.$field.clone()
+                    } else {
+                        $field
+                    };
+                    let mut low_max = max.clone();
+                    low_max.$field = at.clone();
+                    let mut high_min = min.clone();
+                    high_min.$field = at;
+                    ($rect(min, low_max), $rect(high_min, max))
+                }
+            )*
+
+            /// Mirrors the rectangle across the plane perpendicular to `axis`. The points are
+            /// re-sorted along that axis so that an ordered rectangle stays ordered.
+            pub fn reflect_axis(self, axis: $axis) -> $rect<T>
+            where T: Neg<Output = T>
+            {
+                match axis {
+                    $($axis::$variant => self.$flip(),)*
+                }
+            }
+
+            /// Splits the rectangle, which is assumed to be ordered, into the parts below and above
+            /// `at` along `axis`. The split coordinate is clamped to the rectangle.
+            pub fn split_at(self, axis: $axis, at: T) -> ($rect<T>, $rect<T>)
+            where T: Clone + PartialOrd
+            {
+                match axis {
+                    $($axis::$variant => self.$split(at),)*
+                }
+            }
+        }
+    )* };
+}
+
 impl_all! {
     impl Rect2: Vector2(x, y), (x0: T, y0: T | x1: T, y1: T);
     impl Rect3: Vector3(x, y, z), (x0: T, y0: T, z0: T | x1: T, y1: T, z1: T);
 }
 
-//--------------------------------------------------------------------------------------------------
-
-fn partial_max<T: PartialOrd>(a: T, b: T) -> T {
-    if a > b {
-        a
-    } else {
-        b
-    }
+impl_axis! {
+    impl Rect2: Axis2(
+        x: X => (flip_x, flip_x_about, x_range, split_at_x),
+        y: Y => (flip_y, flip_y_about, y_range, split_at_y)
+    );
+    impl Rect3: Axis3(
+        x: X => (flip_x, flip_x_about, x_range, split_at_x),
+        y: Y => (flip_y, flip_y_about, y_range, split_at_y),
+        z: Z => (flip_z, flip_z_about, z_range, split_at_z)
+    );
 }
 
-fn partial_min<T: PartialOrd>(a: T, b: T) -> T {
-    if b < a {
-        b
-    } else {
-        a
-    }
+//--------------------------------------------------------------------------------------------------
+
+/// Strips the brackets and parentheses from a string of the form `[(...), (...)]`, returning the
+/// component lists of the two points.
+fn split_rect_str(s: &str) -> Option<(&str, &str)> {
+    let s = s.trim().strip_prefix('[')?.strip_suffix(']')?;
+    let (a, b) = s.split_once(')')?;
+    let a = a.trim_start().strip_prefix('(')?;
+    let b = b.trim_start().strip_prefix(',')?.trim().strip_prefix('(')?.strip_suffix(')')?;
+    Some((a, b))
 }
 
 fn sort<T: PartialOrd>(a: T, b: T) -> (T, T) {
@@ -441,6 +1267,69 @@ fn sort<T: PartialOrd>(a: T, b: T) -> (T, T) {
 
 //--------------------------------------------------------------------------------------------------
 
+#[test]
+fn test_rect_inflate() {
+    let r = Rect2::new(2, 3, 10, 10);
+    assert_eq!(r.translated(Vector2::new(-2, 1)), Rect2::new(0, 4, 8, 11));
+    assert_eq!(r.inflated(2), Rect2::new(0, 1, 12, 12));
+    assert_eq!(r.deflated(2), Rect2::new(4, 5, 8, 8));
+}
+
+#[test]
+fn test_rect_center_size() {
+    let r = Rect2::from_center_half_extents(Vector2::new(5i32, 5), Vector2::new(2, 3));
+    assert_eq!(r, Rect2::new(3, 2, 7, 8));
+    assert_eq!(r.half_extents(), Vector2::new(2, 3));
+    assert_eq!(Rect2::<i32>::from_center_size(Vector2::new(1, 1), Vector2::new(3, 4)),
+               Rect2::new(0, -1, 3, 3));
+    let r = Rect2::<f64>::from_center_size(Vector2::new(1.0, 1.0), Vector2::new(3.0, 4.0));
+    assert_eq!(r, Rect2::new(-0.5, -1.0, 2.5, 3.0));
+    assert_eq!(r.half_extents(), Vector2::new(1.5, 2.0));
+    assert_eq!(r.center(), Vector2::new(1.0, 1.0));
+}
+
+#[test]
+fn test_rect_origin_size() {
+    let mut r = Rect2::from_origin_size(Vector2::new(10, 20), Vector2::new(30, 40));
+    assert_eq!(r, Rect2::new(10, 20, 40, 60));
+    assert_eq!(r.origin(), Vector2::new(10, 20));
+    r.set_size(Vector2::new(5, 5));
+    assert_eq!(r, Rect2::new(10, 20, 15, 25));
+    assert_eq!(r.size(), Vector2::new(5, 5));
+}
+
+#[test]
+fn test_rect_from_points() {
+    let points = [Vector2::new(3, 1), Vector2::new(-2, 4), Vector2::new(0, -5)];
+    assert_eq!(Rect2::from_points(points), Some(Rect2::new(-2, -5, 3, 4)));
+    assert_eq!(Rect2::<i32>::from_points([]), None);
+    let r: Option<Rect3<f32>> = (0..4).map(|i| Vector3::new(i as f32, 1.0, -(i as f32))).collect();
+    assert_eq!(r, Some(Rect3::new(0.0, 1.0, -3.0, 3.0, 1.0, 0.0)));
+}
+
+#[test]
+fn test_rect_scaled_around() {
+    let r = Rect2::new(0.0, 0.0, 4.0, 2.0);
+    assert_eq!(r.scaled_around(Vector2::new(2.0, 1.0), 2.0), Rect2::new(-2.0, -1.0, 6.0, 3.0));
+    assert_eq!(r.scaled_around(Vector2::new(0.0, 0.0), 0.5), Rect2::new(0.0, 0.0, 2.0, 1.0));
+    assert_eq!(Vector2::new(3, 3).scaled_around(Vector2::new(1, 2), 3), Vector2::new(7, 5));
+}
+
+#[test]
+fn test_rect_intersects() {
+    let cases = [
+        (Rect2::new(0, 1, 80, 81), Rect2::new(20, 21, 100, 101)),
+        (Rect2::new(0, 0, 10, 10), Rect2::new(10, 0, 20, 10)),
+        (Rect2::new(0, 0, 100, 100), Rect2::new(50, 50, 50, 50)),
+        (Rect2::new(0, 0, 100, 100), Rect2::new(80, 80, 20, 20)),
+        (Rect2::new(0, 0, 100, 100), Rect2::new(20, 20, 30, 30)),
+    ];
+    for (a, b) in cases {
+        assert_eq!(a.intersects(&b), a.intersect(b).is_some());
+        assert_eq!(b.intersects(&a), a.intersects(&b));
+    }
+}
+
 #[test]
 fn test_rect_intersection() {
     assert_eq!(Rect2::new(0, 1, 80, 81).intersect(Rect2::new(20, 21, 100, 101)),
@@ -453,6 +1342,39 @@ fn test_rect_intersection() {
     assert_eq!(Rect2::new(0, 0, 10, 10).intersect(Rect2::new(20, 0, 30, 10)), None);
 }
 
+#[cfg(feature = "std")]
+#[test]
+fn test_rect_round_out() {
+    assert_eq!(Rect2::new(0.5f32, -1.5, 2.0, 3.25).round_out(), Rect2::new(0.0, -2.0, 2.0, 4.0));
+    assert_eq!(Rect2::new(0.5f32, -1.5, 2.0, 3.25).round_in(), Rect2::new(1.0, -1.0, 2.0, 3.0));
+    assert_eq!(Rect2::new(0.5f32, -1.5, 2.0, 3.25).convert_round_out::<i32>(),
+               Rect2::new(0, -2, 2, 4));
+    assert_eq!(Rect2::new(0.5f32, -1.5, 2.0, 3.25).convert_round_in::<i32>(),
+               Rect2::new(1, -1, 2, 3));
+    assert_eq!(Rect2::new(-1e10f32, 0.5, 1e10, 1.5).convert_round_out::<i32>(),
+               Rect2::new(i32::MIN, 0, i32::MAX, 2));
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn test_rect_cast() {
+    assert_eq!(Rect2::new(0.5f32, -1.5, 2.0, 300.0).cast::<i16>(), Some(Rect2::new(0, -1, 2, 300)));
+    assert_eq!(Rect2::new(0.5f32, -1.5, 2.0, 300.0).cast::<u8>(), None);
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn test_rect_bounded() {
+    use ::num_traits::Bounded;
+
+    let inverted = Rect2(Vector2::<u8>::max_value(), Vector2::min_value());
+    let bounds = [Vector2::new(3, 9), Vector2::new(7, 2)]
+        .into_iter()
+        .fold(inverted, |r, p| Rect2(r.0.min(p), r.1.max(p)));
+    assert_eq!(bounds, Rect2::new(3, 2, 7, 9));
+    assert_eq!(Rect2::<i8>::min_value(), Rect2::new(-128, -128, -128, -128));
+}
+
 #[test]
 fn test_rect_ordering() {
     assert!(Rect2::new(0, 1, 2, 3).is_ordered());
@@ -464,3 +1386,380 @@ fn test_rect_ordering() {
     assert_eq!(Rect2::new(0, 1, 2, 3).ordered(), Rect2::new(0, 1, 2, 3));
     assert_eq!(Rect2::new(2, 1, 0, 3).ordered(), Rect2::new(0, 1, 2, 3));
 }
+
+#[test]
+fn test_rect_classify() {
+    assert_eq!(Rect2::new(0, 0, 1, 1).classify(), RectKind::Positive);
+    assert_eq!(Rect2::new(0, 0, 1, 0).classify(), RectKind::Flat(Axis2::Y));
+    assert_eq!(Rect3::new(0, 0, 0, 0, 1, -1).classify(), RectKind::Inverted(Axis3::Z));
+    assert_eq!(Rect2::new(0.0, 0.0, f64::NAN, 0.0).classify(), RectKind::Incomparable);
+    assert!(!Rect2::new(0, 0, 1, 1).is_empty());
+    assert!(Rect2::new(0, 0, 1, 0).is_empty());
+    assert!(Rect2::new(0, 0, -1, 1).is_empty());
+}
+
+#[test]
+fn test_rect_flip() {
+    assert_eq!(Rect2::new(1, 2, 4, 8).flip_x(), Rect2::new(-4, 2, -1, 8));
+    assert_eq!(Rect2::new(1, 2, 4, 8).reflect_axis(Axis2::Y), Rect2::new(1, -8, 4, -2));
+    assert!(Rect3::new(1, 2, 3, 4, 5, 6).flip_z().is_ordered());
+    let screen = Rect2::new(0i32, 0, 100, 50);
+    assert_eq!(Rect2::new(10, 5, 30, 15).flip_x_about(screen.center().x),
+               Rect2::new(70, 5, 90, 15));
+    assert_eq!(Rect3::new(0.0, 0.0, 1.0, 1.0, 1.0, 2.0).flip_z_about(0.5),
+               Rect3::new(0.0, 0.0, -1.0, 1.0, 1.0, 0.0));
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic]
+fn test_rect_flip_about_unsigned_overflow() {
+    let _ = Rect2::new(0u32, 0, 10, 10).flip_x_about(2);
+}
+
+#[test]
+fn test_rect_snap_to_grid() {
+    assert_eq!(Rect2::new(-5i32, 3, 9, 8).snap_to_grid(Vector2::new(4, 8)),
+               Rect2::new(-8, 0, 12, 8));
+    #[cfg(feature = "std")]
+    assert_eq!(Rect2::new(0.3f32, 1.0, 0.7, 1.1).snap_to_grid(Vector2::new(0.5, 0.5)),
+               Rect2::new(0.0, 1.0, 1.0, 1.5));
+    assert_eq!(Rect2::new(-5i32, 3, 9, 17).snap_to_grid_in(Vector2::new(4, 8)),
+               Rect2::new(-4, 8, 8, 16));
+    #[cfg(feature = "std")]
+    assert_eq!(Rect2::new(0.3f32, 1.0, 1.7, 1.1).snap_to_grid_in(Vector2::new(0.5, 0.5)),
+               Rect2::new(0.5, 1.0, 1.5, 1.0));
+}
+
+#[test]
+fn test_vec_wrap_into() {
+    let bounds = Rect2::new(-10i8, 0, 118, 100);
+    assert_eq!(Vector2::new(-11i8, -6).wrap_into(&bounds), Vector2::new(117, 94));
+    assert_eq!(Vector2::new(127i8, -128).wrap_into(&bounds), Vector2::new(-1, 72));
+    assert_eq!(Vector2::new(3u8, 1).wrap_into(&Rect2::new(5, 0, 8, 1)), Vector2::new(6, 0));
+    #[cfg(feature = "std")]
+    assert_eq!(Vector2::new(-0.5f32, 2.5).wrap_into(&Rect2::new(0.0, 0.0, 2.0, 1.0)),
+               Vector2::new(1.5, 0.5));
+}
+
+#[test]
+fn test_rect_split() {
+    let r = Rect2::new(0, 0, 10, 4);
+    assert_eq!(r.split_at_x(3), (Rect2::new(0, 0, 3, 4), Rect2::new(3, 0, 10, 4)));
+    assert_eq!(r.split_at(Axis2::Y, 1), (Rect2::new(0, 0, 10, 1), Rect2::new(0, 1, 10, 4)));
+    assert_eq!(r.split_at_y(-5), (Rect2::new(0, 0, 10, 0), r));
+    assert_eq!(r.split_left(12), (r, Rect2::new(10, 0, 10, 4)));
+    let (header, body) = r.split_top(1);
+    let (sidebar, content) = body.split_left(2);
+    assert_eq!(header, Rect2::new(0, 0, 10, 1));
+    assert_eq!(sidebar, Rect2::new(0, 1, 2, 4));
+    assert_eq!(content, Rect2::new(2, 1, 10, 4));
+    let c = Rect3::new(0, 0, 0, 2, 2, 2);
+    assert_eq!(c.split_at_z(1).1, Rect3::new(0, 0, 1, 2, 2, 2));
+}
+
+#[test]
+fn test_rect_grid_cells_float() {
+    let cells = Rect2::new(0.0f32, 0.0, 3.0, 1.0).grid_cells(Vector2::new(2, 2));
+    assert!(cells.eq([
+        Rect2::new(0.0, 0.0, 1.5, 0.5), Rect2::new(1.5, 0.0, 3.0, 0.5),
+        Rect2::new(0.0, 0.5, 1.5, 1.0), Rect2::new(1.5, 0.5, 3.0, 1.0),
+    ]));
+    let cells = Rect2::new(0.0f32, 0.0, 3.0, 1.0).grid_cells(Vector2::new(usize::MAX, 2));
+    assert_eq!(cells.count(), 0);
+}
+
+#[test]
+fn test_rect_quadrants() {
+    let r = Rect2::new(0.0f32, 0.0, 2.0, 4.0);
+    assert_eq!(r.quadrants(), [
+        Rect2::new(0.0, 0.0, 1.0, 2.0), Rect2::new(1.0, 0.0, 2.0, 2.0),
+        Rect2::new(0.0, 2.0, 1.0, 4.0), Rect2::new(1.0, 2.0, 2.0, 4.0),
+    ]);
+    let q = Rect2::new(0i32, 0, 5, 3).quadrants();
+    assert_eq!(q[0], Rect2::new(0, 0, 2, 1));
+    assert_eq!(q[3], Rect2::new(2, 1, 5, 3));
+    let o = Rect3::new(0u8, 0, 0, 4, 4, 4).octants_around(Vector3::new(1, 2, 3));
+    assert_eq!(o[0], Rect3::new(0, 0, 0, 1, 2, 3));
+    assert_eq!(o[5], Rect3::new(1, 0, 3, 4, 2, 4));
+    assert_eq!(o[7], Rect3::new(1, 2, 3, 4, 4, 4));
+}
+
+#[test]
+fn test_rect_normalized() {
+    let atlas_cell = Rect2::new(0.5f32, 0.25, 0.75, 0.5);
+    assert_eq!(atlas_cell.point_at(Vector2::new(0.5, 1.0)), Vector2::new(0.625, 0.5));
+    assert_eq!(atlas_cell.normalized_of(Vector2::new(0.625, 0.5)), Vector2::new(0.5, 1.0));
+    assert_eq!(atlas_cell.normalized_of(Vector2::new(1.0, 0.0)), Vector2::new(2.0, -1.0));
+    assert_eq!(Rect2::new(10, 20, 30, 60).point_at(Vector2::new(1, 0)), Vector2::new(30, 20));
+}
+
+#[test]
+fn test_rect_aspect_ratio() {
+    let video = Rect2::new(0.0f32, 0.0, 200.0, 100.0);
+    let view = Rect2::new(0.0, 0.0, 100.0, 100.0);
+    assert_eq!(video.aspect_ratio(), 2.0);
+    assert_eq!(video.fit_inside(&view), Rect2::new(0.0, 25.0, 100.0, 75.0));
+    assert_eq!(video.fill(&view), Rect2::new(-50.0, 0.0, 150.0, 100.0));
+    assert_eq!(view.fit_inside(&video), Rect2::new(50.0, 0.0, 150.0, 100.0));
+}
+
+#[test]
+fn test_rect_aligned_within() {
+    use crate::align::{Align, Anchor};
+
+    let container = Rect2::new(0i32, 0, 100, 50);
+    let r = Rect2::new(0i32, 0, 11, 10);
+    assert_eq!(r.aligned_within(&container, Anchor::TopLeft), Rect2::new(0, 0, 11, 10));
+    assert_eq!(r.aligned_within(&container, Anchor::Center), Rect2::new(45, 20, 56, 30));
+    assert_eq!(r.aligned_within(&container, Anchor::BottomRight), Rect2::new(89, 40, 100, 50));
+    let r = Rect3::new(0.0f32, 0.0, 0.0, 1.0, 1.0, 1.0);
+    let align = Vector3::new(Align::End, Align::Center, Align::Start);
+    assert_eq!(r.aligned_within(&Rect3::new(0.0, 0.0, 0.0, 4.0, 4.0, 4.0), align),
+               Rect3::new(3.0, 1.5, 0.0, 4.0, 2.5, 1.0));
+}
+
+#[test]
+fn test_rect_edges() {
+    let edges = Rect2::new(0, 0, 2, 1).edges();
+    assert_eq!(edges[0], Segment2(Vector2::new(0, 0), Vector2::new(2, 0)));
+    assert_eq!(edges[2], Segment2(Vector2::new(2, 1), Vector2::new(0, 1)));
+    assert!((0..4).all(|i| edges[i].1 == edges[(i + 1) % 4].0));
+    let faces = Rect3::new(0, 0, 0, 1, 2, 3).faces();
+    assert_eq!(faces[crate::Direction6::Right as usize], Rect3::new(1, 0, 0, 1, 2, 3));
+    assert_eq!(faces[crate::Direction6::Forward as usize], Rect3::new(0, 0, 0, 1, 2, 0));
+}
+
+#[test]
+fn test_rect_signed_distance() {
+    let r = Rect2::new(0u32, 0, 10, 10);
+    assert_eq!(r.distance_squared_to_point(Vector2::new(13, 14)), 25);
+    assert_eq!(r.distance_squared_to_point(Vector2::new(3, 4)), 0);
+    #[cfg(feature = "std")]
+    {
+        let r = Rect2::new(0.0f64, 0.0, 10.0, 4.0);
+        assert_eq!(r.signed_distance(Vector2::new(13.0, 8.0)), 5.0);
+        assert_eq!(r.signed_distance(Vector2::new(5.0, 1.0)), -1.0);
+        assert_eq!(r.signed_distance(Vector2::new(10.0, 2.0)), 0.0);
+    }
+}
+
+#[test]
+fn test_rect_ranges() {
+    let r = Rect2::from((2..5, 1..3));
+    assert_eq!(r, Rect2::new(2, 1, 5, 3));
+    assert_eq!(r.x_range(), 2..5);
+    assert_eq!(r.y_range(), 1..3);
+    assert_eq!(<(_, _)>::from(r), (2..5, 1..3));
+    let r = Rect3::from((0..1, 2..3, 4..5));
+    assert_eq!(r.z_range(), 4..5);
+    assert_eq!(<(_, _, _)>::from(r), (0..1, 2..3, 4..5));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_rect_display() {
+    let r = Rect2::new(1, -2, 3, 4);
+    assert_eq!(r.to_string(), "[(1, -2), (3, 4)]");
+    assert_eq!(r.to_string().parse(), Ok(r));
+    assert_eq!(" [ (1.5,2,3) ,(4, 5 ,6)]".parse(), Ok(Rect3::new(1.5, 2.0, 3.0, 4.0, 5.0, 6.0)));
+    assert_eq!("[(1, 2), (3, 4)]".parse::<Rect3<i32>>(), Err(ParseRectError::Syntax));
+    assert_eq!("[(1, 2, 3), (3, 4)]".parse::<Rect2<i32>>(), Err(ParseRectError::Syntax));
+    assert_eq!("(1, 2), (3, 4)".parse::<Rect2<i32>>(), Err(ParseRectError::Syntax));
+    assert!(matches!("[(1, x), (3, 4)]".parse::<Rect2<i32>>(), Err(ParseRectError::Scalar(_))));
+}
+
+#[test]
+fn test_rect_extend() {
+    let mut bounds = Rect2::from_points([Vector2::new(1, 1)]).unwrap();
+    bounds.extend([Vector2::new(3, -1), Vector2::new(0, 2)]);
+    assert_eq!(bounds, Rect2::new(0, -1, 3, 2));
+    bounds.extend([Rect2::new(9, 9, 9, 20), Rect2::new(2, 2, 5, 4)]);
+    assert_eq!(bounds, Rect2::new(0, -1, 5, 4));
+    let mut empty = Rect3::default();
+    empty.extend([Rect3::new(1, 1, 1, 2, 2, 2)]);
+    assert_eq!(empty, Rect3::new(1, 1, 1, 2, 2, 2));
+}
+
+#[test]
+fn test_rect_difference() {
+    let r = Rect2::new(0, 0, 10, 10);
+    assert!(r.difference(&Rect2::new(2, 3, 5, 6)).eq([
+        Rect2::new(0, 0, 2, 10),
+        Rect2::new(5, 0, 10, 10),
+        Rect2::new(2, 0, 5, 3),
+        Rect2::new(2, 6, 5, 10),
+    ]));
+    assert!(r.difference(&Rect2::new(5, -5, 15, 15)).eq([Rect2::new(0, 0, 5, 10)]));
+    assert!(r.difference(&Rect2::new(20, 20, 30, 30)).eq([r]));
+    assert_eq!(r.difference(&Rect2::new(-1, -1, 11, 11)).count(), 0);
+    let c = Rect3::new(0, 0, 0, 3, 3, 3);
+    let pieces = c.difference(&Rect3::new(1, 1, 1, 2, 2, 2));
+    assert_eq!(pieces.map(|p| p.volume()).sum::<i32>(), 26);
+}
+
+#[test]
+fn test_rect_slice_extrude() {
+    let r = Rect3::new(1, 2, 3, 4, 5, 6);
+    assert_eq!(r.slice(Axis3::X), Rect2::new(2, 3, 5, 6));
+    assert_eq!(r.slice(Axis3::Y), Rect2::new(1, 3, 4, 6));
+    assert_eq!(r.slice(Axis3::Z), Rect2::new(1, 2, 4, 5));
+    for axis in [Axis3::X, Axis3::Y, Axis3::Z] {
+        let range = r.0[axis as usize]..r.1[axis as usize];
+        assert_eq!(r.slice(axis).extrude(axis, range), r);
+    }
+}
+
+#[test]
+fn test_rect_nine_slice() {
+    let slices = Rect2::new(0, 0, 10, 8).nine_slice(Rect2::new(1, 2, 3, 1));
+    assert_eq!(slices[crate::Anchor::TopLeft as usize], Rect2::new(0, 0, 1, 2));
+    assert_eq!(slices[crate::Anchor::Top as usize], Rect2::new(1, 0, 7, 2));
+    assert_eq!(slices[crate::Anchor::Center as usize], Rect2::new(1, 2, 7, 7));
+    assert_eq!(slices[crate::Anchor::BottomRight as usize], Rect2::new(7, 7, 10, 8));
+    assert_eq!(slices.iter().map(|r| r.area()).sum::<i32>(), 80);
+}
+
+#[test]
+fn test_rect_overlap() {
+    let a = Rect2::new(0, 0, 4, 4);
+    assert_eq!(a.overlap_area(&Rect2::new(2, 1, 6, 3)), 4);
+    assert_eq!(a.overlap_area(&Rect2::new(4, 0, 6, 4)), 0);
+    assert_eq!(a.overlap_area(&a), a.area());
+    let b = Rect3::new(0.0, 0.0, 0.0, 2.0, 2.0, 2.0);
+    assert_eq!(b.overlap_volume(&Rect3::new(1.0, 1.0, 1.5, 3.0, 3.0, 3.0)), 0.5);
+    assert_eq!(b.overlap_volume(&Rect3::new(3.0, 0.0, 0.0, 4.0, 1.0, 1.0)), 0.0);
+}
+
+#[test]
+fn test_rect_empty() {
+    let points = [Vector2::new(3, -1), Vector2::new(-2, 4), Vector2::new(0, 0)];
+    let mut r = Rect2::<i32>::EMPTY;
+    assert!(r.is_empty());
+    r.extend(points);
+    assert_eq!(r, Rect2::new(-2, -1, 3, 4));
+    assert_eq!(Rect2::<i32>::EMPTY.union(&r), r);
+    let mut f = Rect3::<f32>::EMPTY;
+    f.extend([Vector3::new(1.0, 2.0, 3.0)]);
+    assert_eq!(f, Rect3::new(1.0, 2.0, 3.0, 1.0, 2.0, 3.0));
+}
+
+#[test]
+fn test_rect_union() {
+    let empty = Rect2::new(5, 5, 5, 5);
+    let a = Rect2::new(0, 0, 2, 2);
+    let b = Rect2::new(1, -1, 4, 1);
+    assert_eq!(a.union(&b), Rect2::new(0, -1, 4, 2));
+    assert_eq!(empty.union(&a), a);
+    assert_eq!(a.union(&empty), a);
+    assert_eq!(empty.expand(a), empty);
+    assert_eq!([empty, a, empty, b].iter().fold(empty, |r, x| r.union(x)), Rect2::new(0, -1, 4, 2));
+}
+
+#[test]
+fn test_rect_expand_nan() {
+    let a = Rect2::new(0.0f32, f32::NAN, 1.0, 1.0);
+    let b = Rect2::new(-1.0, 0.5, 0.5, 2.0);
+    assert_eq!(a.expand_ignore_nan(b), Rect2::new(-1.0, 0.5, 1.0, 2.0));
+    assert!(a.expand_propagate_nan(b).0.y.is_nan());
+    assert_eq!(a.expand_propagate_nan(b).1, Vector2::new(1.0, 2.0));
+}
+
+#[test]
+fn test_rect_distance_to_point() {
+    use crate::ops::{L1, LInf};
+
+    let r = Rect2::new(0u32, 0, 10, 10);
+    assert_eq!(r.distance_to_point(Vector2::new(5, 5), L1), 0);
+    assert_eq!(r.distance_to_point(Vector2::new(13, 14), L1), 7);
+    assert_eq!(r.distance_to_point(Vector2::new(13, 14), LInf), 4);
+    #[cfg(feature = "std")]
+    {
+        let r = Rect2::new(0.0f32, 0.0, 1.0, 1.0);
+        assert_eq!(r.distance_to_point(Vector2::new(-3.0, 5.0), crate::ops::L2), 5.0);
+    }
+}
+
+#[test]
+fn test_rect_lerp() {
+    assert_eq!(Rect2::new(0.0f32, 0.0, 1.0, 1.0).lerp(Rect2::new(2.0, 2.0, 5.0, 3.0), 0.5),
+               Rect2::new(1.0, 1.0, 3.0, 2.0));
+    let a = Rect3::new(0.0f64, 0.0, 0.0, 1.0, 1.0, 1.0);
+    let b = Rect3::new(-4.0, 0.0, 4.0, 5.0, 1.0, 9.0);
+    assert_eq!(crate::ops::lerp(a, b, 0.25), Rect3::new(-1.0, 0.0, 1.0, 2.0, 1.0, 3.0));
+    assert_eq!(a.lerp(b, 1.0), b);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_rect_distance() {
+    use crate::ops::{Distance, MetricSpace};
+
+    let a = Rect2::new(0.0f64, 0.0, 2.0, 2.0);
+    assert_eq!(a.distance_to(&Vector2::new(5.0, 6.0)), 5.0);
+    assert_eq!(Vector2::new(1.0, -3.0).distance_squared_to(&a), 9.0);
+    assert_eq!(a.distance_to(&Rect2::new(1.0, 1.0, 3.0, 3.0)), 0.0);
+    assert_eq!(a.distance_to(&Rect2::new(5.0, 6.0, 7.0, 8.0)), 5.0);
+    assert_eq!(Vector2::new(5.0f64, 6.0).distance(Vector2::new(2.0, 2.0)), 5.0);
+    assert_eq!(Vector2::new(5.0f64, 6.0).distance_to(&a), 5.0);
+}
+
+#[test]
+fn test_rect_center_float() {
+    assert_eq!(Rect2::new(1.0f32, -f32::MAX, 2.0, f32::MAX).center(), Vector2::new(1.5, 0.0));
+}
+
+#[test]
+fn test_rect_clamp_point() {
+    let r = Rect2::new(0, 0, 10, 5);
+    assert_eq!(r.clamp_point(Vector2::new(3, 4)), Vector2::new(3, 4));
+    assert_eq!(r.clamp_point(Vector2::new(-3, 9)), Vector2::new(0, 5));
+    assert_eq!(r.clamp_point(Vector2::new(12, 2)), Vector2::new(10, 2));
+}
+
+#[test]
+fn test_rect_clip_all() {
+    let clip = Rect2::new(0, 0, 10, 10);
+    let mut rects = [
+        Rect2::new(-5, -5, 5, 5),
+        Rect2::new(20, 20, 30, 30),
+        Rect2::new(2, 2, 1, 8),
+        Rect2::new(8, 1, 12, 3),
+    ];
+    let len = clip.clip_all(&mut rects);
+    assert_eq!(rects[..len], [Rect2::new(0, 0, 5, 5), Rect2::new(8, 1, 10, 3)]);
+    assert_eq!(Rect2::new(0, 0, 0, 10).clip_all(&mut rects), 0);
+}
+
+#[test]
+fn test_rect_contains_point() {
+    let r = Rect2::new(0, 0, 4, 4);
+    assert!(r.contains_point(&Vector2::new(0, 3)));
+    assert!(!r.contains_point(&Vector2::new(0, 4)));
+    assert!(r.contains_point_inclusive(&Vector2::new(0, 4)));
+    assert!(!r.contains_point_exclusive(&Vector2::new(0, 3)));
+    assert!(r.contains_point_exclusive(&Vector2::new(1, 3)));
+    assert!(!Rect2::new(0.0, 0.0, 1.0, 1.0).contains_point(&Vector2::new(f32::NAN, 0.5)));
+    assert!(Contains::contains(&r, &Vector2::new(0, 3)));
+    assert!(!Contains::contains(&r, &Vector2::new(0, 4)));
+}
+
+#[test]
+fn test_rect_contains_rect() {
+    let r = Rect2::new(0, 0, 10, 10);
+    assert!(r.contains_rect(&Rect2::new(0, 2, 10, 5)));
+    assert!(r.contains_rect(&r));
+    assert!(!r.contains_rect(&Rect2::new(5, 5, 11, 6)));
+    assert!(!r.contains_rect(&Rect2::new(5, 5, 5, 6)));
+    assert!(!Rect2::new(10, 10, 0, 0).contains_rect(&Rect2::new(2, 2, 3, 3)));
+}
+
+#[test]
+fn test_rect_contains() {
+    let r = Rect2::new(0, 0, 10, 10);
+    assert!(r.contains(&Vector2::new(0, 9)));
+    assert!(!r.contains(&Vector2::new(10, 0)));
+    assert!(r.contains(&Rect2::new(2, 2, 10, 5)));
+    assert!(!r.contains(&Rect2::new(-1, 2, 10, 5)));
+    assert_eq!(Rect2::new(5, 0, 0, 5).bounding_rect(), Rect2::new(0, 0, 5, 5));
+}
@@ -0,0 +1,52 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use num_traits::{
+    Bounded,
+    NumCast,
+    ToPrimitive,
+};
+
+use crate::rect::{Rect2, Rect3};
+use crate::vec::{Vector2, Vector3};
+
+/// Implements all relevant `num-traits` functionality for rects.
+macro_rules! impl_all {
+    { $(impl $rect:ident: $vec:ident;)* } => { $(
+        impl<T> $rect<T> {
+            /// Converts the rectangle's scalar components to another numeric type using
+            /// [NumCast]. Returns `None` if any component cannot be represented by the target
+            /// type.
+            pub fn cast<U>(self) -> Option<$rect<U>>
+            where T: ToPrimitive,
+                  U: NumCast
+            {
+                Some($rect(self.0.cast()?, self.1.cast()?))
+            }
+        }
+
+        impl<T> Bounded for $rect<T>
+        where T: Bounded
+        {
+            /// Returns a rectangle with both points at the greatest representable value.
+            fn max_value() -> $rect<T> {
+                $rect($vec::max_value(), $vec::max_value())
+            }
+
+            /// Returns a rectangle with both points at the least representable value.
+            fn min_value() -> $rect<T> {
+                $rect($vec::min_value(), $vec::min_value())
+            }
+        }
+    )* };
+}
+
+impl_all! {
+    impl Rect2: Vector2;
+    impl Rect3: Vector3;
+}
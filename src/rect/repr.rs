@@ -0,0 +1,146 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::ops::{Add, Sub};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::rect::{Rect2, Rect3};
+use crate::vec::{Vector2, Vector3};
+
+/// Wrapper which serializes a rectangle as a struct with `min` and `max` fields, rather than as
+/// a pair of points. Only available with the `serde` feature.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct RectMinMax<R>(pub R);
+
+/// Wrapper which serializes a rectangle as a struct with `origin` and `size` fields, rather than
+/// as a pair of points. Only available with the `serde` feature.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct RectOriginSize<R>(pub R);
+
+#[derive(Serialize)]
+#[serde(rename = "Rect")]
+struct MinMaxRef<'a, V> {
+    min: &'a V,
+    max: &'a V,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Rect")]
+struct MinMax<V> {
+    min: V,
+    max: V,
+}
+
+#[derive(Serialize)]
+#[serde(rename = "Rect")]
+struct OriginSizeRef<'a, V> {
+    origin: &'a V,
+    size: V,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Rect")]
+struct OriginSize<V> {
+    origin: V,
+    size: V,
+}
+
+macro_rules! impl_repr {
+    { $($rect:ident: $vec:ident),* } => { $(
+        impl<'de, T: Deserialize<'de>> Deserialize<'de> for RectMinMax<$rect<T>> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let repr = MinMax::<$vec<T>>::deserialize(deserializer)?;
+                Ok(RectMinMax($rect(repr.min, repr.max)))
+            }
+        }
+
+        impl<'de, T> Deserialize<'de> for RectOriginSize<$rect<T>>
+        where T: Add<Output = T> + Clone + Deserialize<'de>
+        {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let repr = OriginSize::<$vec<T>>::deserialize(deserializer)?;
+                let max = repr.origin.clone() + repr.size;
+                Ok(RectOriginSize($rect(repr.origin, max)))
+            }
+        }
+
+        impl<T> From<$rect<T>> for RectMinMax<$rect<T>> {
+            fn from(rect: $rect<T>) -> RectMinMax<$rect<T>> {
+                RectMinMax(rect)
+            }
+        }
+
+        impl<T> From<$rect<T>> for RectOriginSize<$rect<T>> {
+            fn from(rect: $rect<T>) -> RectOriginSize<$rect<T>> {
+                RectOriginSize(rect)
+            }
+        }
+
+        impl<T> From<RectMinMax<$rect<T>>> for $rect<T> {
+            fn from(repr: RectMinMax<$rect<T>>) -> $rect<T> {
+                repr.0
+            }
+        }
+
+        impl<T> From<RectOriginSize<$rect<T>>> for $rect<T> {
+            fn from(repr: RectOriginSize<$rect<T>>) -> $rect<T> {
+                repr.0
+            }
+        }
+
+        impl<T: Serialize> Serialize for RectMinMax<$rect<T>> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                MinMaxRef { min: &self.0.0, max: &self.0.1 }.serialize(serializer)
+            }
+        }
+
+        impl<T> Serialize for RectOriginSize<$rect<T>>
+        where T: Clone + Serialize + Sub<Output = T>
+        {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let size = self.0.1.clone() - self.0.0.clone();
+                OriginSizeRef { origin: &self.0.0, size }.serialize(serializer)
+            }
+        }
+    )* };
+}
+
+impl_repr!(Rect2: Vector2, Rect3: Vector3);
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_rect_min_max() {
+    let r = RectMinMax(Rect2::new(1, 2, 4, 6));
+    let json = serde_json::to_string(&r).unwrap();
+    assert_eq!(json, r#"{"min":{"x":1,"y":2},"max":{"x":4,"y":6}}"#);
+    assert_eq!(serde_json::from_str::<RectMinMax<Rect2<i32>>>(&json).unwrap(), r);
+
+    let r = RectMinMax(Rect3::new(1, 2, 3, 4, 6, 8));
+    let json = serde_json::to_string(&r).unwrap();
+    assert_eq!(json, r#"{"min":{"x":1,"y":2,"z":3},"max":{"x":4,"y":6,"z":8}}"#);
+    assert_eq!(serde_json::from_str::<RectMinMax<Rect3<i32>>>(&json).unwrap(), r);
+}
+
+#[test]
+fn test_rect_origin_size() {
+    let r = RectOriginSize(Rect2::new(1, 2, 4, 6));
+    let json = serde_json::to_string(&r).unwrap();
+    assert_eq!(json, r#"{"origin":{"x":1,"y":2},"size":{"x":3,"y":4}}"#);
+    let decoded = serde_json::from_str::<RectOriginSize<Rect2<i32>>>(&json).unwrap();
+    assert_eq!(decoded, r);
+    assert_eq!(decoded.0.1, Vector2::new(4, 6));
+
+    let r = RectOriginSize(Rect3::new(1, 2, 3, 4, 6, 8));
+    let json = serde_json::to_string(&r).unwrap();
+    assert_eq!(json, r#"{"origin":{"x":1,"y":2,"z":3},"size":{"x":3,"y":4,"z":5}}"#);
+    let decoded = serde_json::from_str::<RectOriginSize<Rect3<i32>>>(&json).unwrap();
+    assert_eq!(decoded, r);
+    assert_eq!(decoded.0.1, Vector3::new(4, 6, 8));
+}
@@ -0,0 +1,79 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::ops::{Add, Neg, Sub};
+
+use crate::rect::Rect2;
+use crate::vec::Vector2;
+
+/// Rotation by a whole number of quarter turns counter-clockwise, assuming the y axis points up.
+/// Applying a rotation is exact for any scalar type, which makes it suitable for tile and
+/// texture atlas orientation flags.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Rotation90 {
+    /// No rotation.
+    #[default]
+    R0,
+    /// A quarter turn counter-clockwise.
+    R90,
+    /// A half turn.
+    R180,
+    /// Three quarter turns counter-clockwise, or one quarter turn clockwise.
+    R270,
+}
+
+impl Rotation90 {
+    /// All rotations in counter-clockwise order, starting with `R0`.
+    pub const ALL: [Rotation90; 4] = [Self::R0, Self::R90, Self::R180, Self::R270];
+
+    /// Rotates `v` about the origin.
+    pub fn apply<T>(self, v: Vector2<T>) -> Vector2<T>
+    where T: Neg<Output = T>
+    {
+        match self {
+            Self::R0 => v,
+            Self::R90 => v.rotate_90_ccw(),
+            Self::R180 => v.rotate_180(),
+            Self::R270 => v.rotate_90_cw(),
+        }
+    }
+
+    /// Rotates `rect` about `pivot`. The corners of the result are re-sorted so that an ordered
+    /// rectangle stays ordered.
+    pub fn apply_to_rect<T>(self, rect: Rect2<T>, pivot: Vector2<T>) -> Rect2<T>
+    where T: Add<Output = T> + Clone + Neg<Output = T> + PartialOrd + Sub<Output = T>
+    {
+        let rotate = |v: Vector2<T>| self.apply(v - pivot.clone()) + pivot.clone();
+        Rect2(rotate(rect.0), rotate(rect.1)).partially_ordered()
+    }
+
+    /// Returns the rotation which undoes `self`.
+    pub const fn inverse(self) -> Rotation90 {
+        Self::ALL[(4 - self as usize) % 4]
+    }
+
+    /// Returns the rotation equivalent to applying `self` and then `rhs`.
+    pub const fn then(self, rhs: Rotation90) -> Rotation90 {
+        Self::ALL[(self as usize + rhs as usize) % 4]
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_rotation90() {
+    let v = Vector2::new(3, 1);
+    assert_eq!(Rotation90::R90.apply(v), Vector2::new(-1, 3));
+    assert!(Rotation90::ALL.iter().all(|r| r.inverse().apply(r.apply(v)) == v));
+    assert_eq!(Rotation90::R90.then(Rotation90::R270), Rotation90::R0);
+    assert_eq!(Rotation90::R180.then(Rotation90::R270), Rotation90::R90);
+    let r = Rect2::new(2, 0, 4, 1);
+    assert_eq!(Rotation90::R90.apply_to_rect(r, Vector2::new(2, 0)), Rect2::new(1, 0, 2, 2));
+    assert_eq!(Rotation90::R180.apply_to_rect(r, Vector2::new(0, 0)), Rect2::new(-4, -1, -2, 0));
+}
@@ -0,0 +1,437 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::cmp::Ordering;
+
+#[cfg(feature = "std")]
+use crate::ops::{Distance, InnerSpace};
+use crate::ops::{BoundingRect, ClosestPoint, Contains, Dot, Orient2d};
+use crate::rect::{Rect2, Rect3};
+use crate::vec::{Vector2, Vector3};
+
+/// 2-dimensional line segment structure defined by its two end points.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Segment2<T>(pub Vector2<T>, pub Vector2<T>);
+
+/// 3-dimensional line segment structure defined by its two end points.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Segment3<T>(pub Vector3<T>, pub Vector3<T>);
+
+//--------------------------------------------------------------------------------------------------
+
+/// Implements common functions for segments.
+macro_rules! impl_all {
+    { $(impl $seg:ident: $rect:ident;)* } => { $(
+        impl<T> $seg<T> {
+            /// Returns the segment with its end points swapped.
+            pub fn reversed(self) -> $seg<T> {
+                $seg(self.1, self.0)
+            }
+        }
+
+        impl<T> BoundingRect for $seg<T>
+        where T: Clone + PartialOrd
+        {
+            type Output = $rect<T>;
+
+            fn bounding_rect(&self) -> $rect<T> {
+                $rect(self.0.clone().min(self.1.clone()), self.0.clone().max(self.1.clone()))
+            }
+        }
+    )* };
+}
+
+/// Implements functions for segments with floating point components.
+macro_rules! impl_float {
+    { $(impl $seg:ident<$t:ident>: $vec:ident;)* } => { $(
+        impl $seg<$t> {
+            /// Returns the point at parameter `t` along the segment, where `0` is `self.0` and
+            /// `1` is `self.1`.
+            pub fn point_at(&self, t: $t) -> $vec<$t> {
+                self.0 + (self.1 - self.0) * t
+            }
+        }
+
+        impl ClosestPoint<$vec<$t>> for $seg<$t> {
+            fn closest_point(&self, point: &$vec<$t>) -> $vec<$t> {
+                let d = self.1 - self.0;
+                let len_squared = d.dot(d);
+                if len_squared == 0.0 {
+                    return self.0;
+                }
+                let t = ((*point - self.0).dot(d) / len_squared).clamp(0.0, 1.0);
+                self.point_at(t)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl Distance<$vec<$t>> for $seg<$t> {
+            type Output = $t;
+
+            fn distance_to(&self, rhs: &$vec<$t>) -> $t {
+                self.distance_squared_to(rhs).sqrt()
+            }
+
+            fn distance_squared_to(&self, rhs: &$vec<$t>) -> $t {
+                (*rhs - self.closest_point(rhs)).magnitude_squared()
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl Distance<$seg<$t>> for $vec<$t> {
+            type Output = $t;
+
+            fn distance_to(&self, rhs: &$seg<$t>) -> $t {
+                rhs.distance_to(self)
+            }
+
+            fn distance_squared_to(&self, rhs: &$seg<$t>) -> $t {
+                rhs.distance_squared_to(self)
+            }
+        }
+    )* };
+}
+
+/// Implements functions for 2-dimensional segments with floating point components.
+macro_rules! impl_float_2d {
+    { $($t:ident),* } => { $(
+        impl Segment2<$t> {
+            /// Returns the part of the segment which lies within `rect`, which is assumed to be
+            /// ordered, or `None` if the segment lies entirely outside it. This uses the
+            /// Liang-Barsky algorithm. The end points of the result keep the segment's direction.
+            pub fn clip_to_rect(&self, rect: &Rect2<$t>) -> Option<Segment2<$t>> {
+                let d = self.1 - self.0;
+                let (mut t0, mut t1) = (0.0, 1.0);
+                let edges = [
+                    (-d.x, self.0.x - rect.0.x),
+                    (d.x, rect.1.x - self.0.x),
+                    (-d.y, self.0.y - rect.0.y),
+                    (d.y, rect.1.y - self.0.y),
+                ];
+
+                for (p, q) in edges {
+                    if p == 0.0 {
+                        if q < 0.0 {
+                            return None;
+                        }
+                    } else {
+                        let r = q / p;
+                        if p < 0.0 {
+                            t0 = r.max(t0);
+                        } else {
+                            t1 = r.min(t1);
+                        }
+                        if t0 > t1 {
+                            return None;
+                        }
+                    }
+                }
+
+                let start = if t0 > 0.0 { self.point_at(t0) } else { self.0 };
+                let end = if t1 < 1.0 { self.point_at(t1) } else { self.1 };
+                Some(Segment2(start, end))
+            }
+
+            /// Returns the first point along `self` at which it touches `rhs`, or `None` if the
+            /// segments do not touch. See [intersect_params](Self::intersect_params).
+            pub fn intersect(&self, rhs: &Segment2<$t>) -> Option<Vector2<$t>> {
+                let (t, _) = self.intersect_params(rhs)?;
+                Some(self.point_at(t))
+            }
+
+            /// Returns the parameters `(t, u)` such that `self.point_at(t)` and
+            /// `rhs.point_at(u)` are the same point of intersection, or `None` if the segments
+            /// do not touch. If the segments are collinear and overlap, the point of the overlap
+            /// which is closest to `self.0` is used. Parallelism is tested exactly, without any
+            /// tolerance.
+            pub fn intersect_params(&self, rhs: &Segment2<$t>) -> Option<($t, $t)> {
+                let cross = |a: Vector2<$t>, b: Vector2<$t>| a.x * b.y - a.y * b.x;
+                let (r, s) = (self.1 - self.0, rhs.1 - rhs.0);
+                let qp = rhs.0 - self.0;
+                let denom = cross(r, s);
+                let unit = 0.0..=1.0;
+
+                if denom != 0.0 {
+                    let (t, u) = (cross(qp, s) / denom, cross(qp, r) / denom);
+                    return if unit.contains(&t) && unit.contains(&u) { Some((t, u)) } else { None };
+                }
+                if cross(qp, r) != 0.0 || cross(qp, s) != 0.0 {
+                    return None;
+                }
+
+                // The segments are collinear. Find the first parameter along `self` that lies
+                // within `rhs`, then project that point onto `rhs`.
+                let (rr, ss) = (r.dot(r), s.dot(s));
+                let t = if rr == 0.0 {
+                    0.0
+                } else {
+                    let t0 = qp.dot(r) / rr;
+                    let t1 = t0 + s.dot(r) / rr;
+                    let (lo, hi) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+                    if hi < 0.0 || lo > 1.0 {
+                        return None;
+                    }
+                    lo.max(0.0)
+                };
+                let u = if ss == 0.0 { 0.0 } else { (self.point_at(t) - rhs.0).dot(s) / ss };
+                if !unit.contains(&u) || (ss == 0.0 && self.point_at(t) != rhs.0) {
+                    return None;
+                }
+                Some((t, u))
+            }
+        }
+    )* };
+}
+
+/// Implements functions for 3-dimensional segments with floating point components.
+macro_rules! impl_float_3d {
+    { $($t:ident),* } => { $(
+        impl Segment3<$t> {
+            /// Iterates over the unit voxels crossed by the segment, in order from `self.0` to
+            /// `self.1`, using the Amanatides-Woo traversal. Voxel `v` covers the points from `v`
+            /// inclusive to `v + 1` exclusive on each axis. Each voxel is paired with the
+            /// parameter at which the segment enters it, as for [point_at](Self::point_at), so
+            /// the first voxel is entered at `0`. Coordinates beyond the range of `i32` saturate.
+            #[cfg(feature = "std")]
+            pub fn voxels(&self) -> impl Iterator<Item = (Vector3<i32>, $t)> {
+                let d = self.1 - self.0;
+                let mut cell = Vector3::new(self.0.x.floor() as i32, self.0.y.floor() as i32,
+                                            self.0.z.floor() as i32);
+                let mut t_max = Vector3::new(<$t>::INFINITY, <$t>::INFINITY, <$t>::INFINITY);
+                let mut t_delta = t_max;
+                let mut step = Vector3::new(0, 0, 0);
+                for axis in 0..3 {
+                    if d[axis] > 0.0 {
+                        step[axis] = 1;
+                        t_delta[axis] = 1.0 / d[axis];
+                        t_max[axis] = (cell[axis] as $t + 1.0 - self.0[axis]) / d[axis];
+                    } else if d[axis] < 0.0 {
+                        step[axis] = -1;
+                        t_delta[axis] = -1.0 / d[axis];
+                        t_max[axis] = (cell[axis] as $t - self.0[axis]) / d[axis];
+                    }
+                }
+                let mut t = Some(0.0);
+
+                core::iter::from_fn(move || {
+                    let entry = t?;
+                    let item = (cell, entry);
+                    let axis = if t_max.x <= t_max.y && t_max.x <= t_max.z {
+                        0
+                    } else if t_max.y <= t_max.z {
+                        1
+                    } else {
+                        2
+                    };
+                    if t_max[axis] > 1.0 {
+                        t = None;
+                    } else {
+                        t = Some(t_max[axis]);
+                        cell[axis] = cell[axis].saturating_add(step[axis]);
+                        t_max[axis] += t_delta[axis];
+                    }
+                    Some(item)
+                })
+            }
+        }
+    )* };
+}
+
+/// Implements functions for 2-dimensional segments with integer components.
+macro_rules! impl_int_2d {
+    { $($t:ident),* } => { $(
+        impl Segment2<$t> {
+            /// Iterates over every grid cell touched by the segment, treating each end point as the
+            /// center of its cell. Unlike a Bresenham line, this is conservative: consecutive cells
+            /// always share an edge, and where the segment passes exactly through a corner, both
+            /// cells beside the corner are visited before the diagonal cell. Iteration starts at
+            /// `self.0` and ends at `self.1`.
+            pub fn supercover(&self) -> impl Iterator<Item = Vector2<$t>> {
+                let (mut x, mut y) = (self.0.x as i128, self.0.y as i128);
+                let (dx, dy) = (self.1.x as i128 - x, self.1.y as i128 - y);
+                let (nx, ny, sx, sy) = (dx.abs(), dy.abs(), dx.signum(), dy.signum());
+                let (mut ix, mut iy) = (0, 0);
+                let mut cells = [(x, y); 3];
+                let (mut head, mut len) = (0, 1);
+
+                core::iter::from_fn(move || {
+                    if head == len {
+                        if ix == nx && iy == ny {
+                            return None;
+                        }
+                        // Compare the crossings of the next vertical and horizontal cell edges.
+                        let decision = (1 + 2 * ix) * ny - (1 + 2 * iy) * nx;
+                        if decision == 0 {
+                            cells = [(x + sx, y), (x, y + sy), (x + sx, y + sy)];
+                            len = 3;
+                            ix += 1;
+                            iy += 1;
+                        } else if decision < 0 {
+                            cells[0] = (x + sx, y);
+                            len = 1;
+                            ix += 1;
+                        } else {
+                            cells[0] = (x, y + sy);
+                            len = 1;
+                            iy += 1;
+                        }
+                        (x, y) = cells[len - 1];
+                        head = 0;
+                    }
+                    let (cx, cy) = cells[head];
+                    head += 1;
+                    Some(Vector2::new(cx as $t, cy as $t))
+                })
+            }
+        }
+    )* };
+}
+
+impl_all! {
+    impl Segment2: Rect2;
+    impl Segment3: Rect3;
+}
+
+impl_float! {
+    impl Segment2<f32>: Vector2;
+    impl Segment2<f64>: Vector2;
+    impl Segment3<f32>: Vector3;
+    impl Segment3<f64>: Vector3;
+}
+
+impl_float_2d!(f32, f64);
+impl_float_3d!(f32, f64);
+impl_int_2d!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl<T> Contains<Vector2<T>> for Segment2<T>
+where T: Clone + PartialOrd,
+      Vector2<T>: Orient2d
+{
+    /// Tests whether `rhs` lies exactly on the segment, including its end points. This is exact for
+    /// integer components.
+    fn contains(&self, rhs: &Vector2<T>) -> bool {
+        self.0.orient2d(&self.1, rhs) == Some(Ordering::Equal)
+            && self.bounding_rect().contains_point_inclusive(rhs)
+    }
+}
+
+impl<T> Contains<Vector3<T>> for Segment3<T>
+where T: Clone + PartialOrd,
+      Vector2<T>: Orient2d
+{
+    /// Tests whether `rhs` lies exactly on the segment, including its end points. The points are
+    /// collinear if they are collinear in each of the three axis-aligned planes. This is exact for
+    /// integer components.
+    fn contains(&self, rhs: &Vector3<T>) -> bool {
+        let (a, b) = (&self.0, &self.1);
+        let collinear = |u: fn(&Vector3<T>) -> Vector2<T>| {
+            u(a).orient2d(&u(b), &u(rhs)) == Some(Ordering::Equal)
+        };
+        collinear(|v| Vector2::new(v.x.clone(), v.y.clone()))
+            && collinear(|v| Vector2::new(v.y.clone(), v.z.clone()))
+            && collinear(|v| Vector2::new(v.z.clone(), v.x.clone()))
+            && self.bounding_rect().contains_point_inclusive(rhs)
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_segment_closest_point() {
+    let s = Segment2(Vector2::new(0.0f32, 0.0), Vector2::new(4.0, 0.0));
+    assert_eq!(s.closest_point(&Vector2::new(1.0, 3.0)), Vector2::new(1.0, 0.0));
+    assert_eq!(s.closest_point(&Vector2::new(-2.0, 1.0)), Vector2::new(0.0, 0.0));
+    assert_eq!(s.reversed().closest_point(&Vector2::new(9.0, 1.0)), Vector2::new(4.0, 0.0));
+    assert_eq!(s.reversed().bounding_rect(), Rect2::new(0.0, 0.0, 4.0, 0.0));
+    #[cfg(feature = "std")]
+    assert_eq!(Vector2::new(7.0, 4.0).distance_to(&s), 5.0);
+}
+
+#[test]
+fn test_segment_contains() {
+    let s = Segment2(Vector2::new(0, 0), Vector2::new(4, 2));
+    assert!(s.contains(&Vector2::new(2, 1)));
+    assert!(s.contains(&Vector2::new(4, 2)));
+    assert!(!s.contains(&Vector2::new(6, 3)));
+    assert!(!s.contains(&Vector2::new(2, 2)));
+    let s = Segment3(Vector3::new(0, 0, 0), Vector3::new(2, 4, 6));
+    assert!(s.contains(&Vector3::new(1, 2, 3)));
+    assert!(!s.contains(&Vector3::new(1, 2, 2)));
+    assert!(!s.contains(&Vector3::new(-1, -2, -3)));
+
+    // Unsigned and extreme components must neither underflow nor overflow.
+    let s = Segment2(Vector2::new(10u32, 10), Vector2::new(5, 5));
+    assert!(s.contains(&Vector2::new(7, 7)));
+    assert!(!s.contains(&Vector2::new(7, 8)));
+    let s = Segment2(Vector2::new(i32::MIN, i32::MIN), Vector2::new(i32::MAX, i32::MAX));
+    assert!(s.contains(&Vector2::new(0, 0)));
+    assert!(!s.contains(&Vector2::new(0, 1)));
+    let s = Segment3(Vector3::new(9u64, 9, 9), Vector3::new(u64::MAX, u64::MAX, u64::MAX));
+    assert!(s.contains(&Vector3::new(u64::MAX - 1, u64::MAX - 1, u64::MAX - 1)));
+    assert!(!s.contains(&Vector3::new(10, 10, 11)));
+}
+
+#[test]
+fn test_segment_clip_to_rect() {
+    let s = |x0: f32, y0, x1, y1| Segment2(Vector2::new(x0, y0), Vector2::new(x1, y1));
+    let r = Rect2::new(0.0f32, 0.0, 4.0, 2.0);
+    assert_eq!(s(-2.0, 1.0, 6.0, 1.0).clip_to_rect(&r), Some(s(0.0, 1.0, 4.0, 1.0)));
+    assert_eq!(s(3.0, 1.0, 1.0, 3.0).clip_to_rect(&r), Some(s(3.0, 1.0, 2.0, 2.0)));
+    assert_eq!(s(1.0, 1.0, 2.0, 1.5).clip_to_rect(&r), Some(s(1.0, 1.0, 2.0, 1.5)));
+    assert_eq!(s(-1.0, 3.0, 5.0, 3.0).clip_to_rect(&r), None);
+    assert_eq!(s(3.5, 3.0, 5.0, 1.5).clip_to_rect(&r), None);
+    assert_eq!(s(3.0, 3.0, 5.0, 1.0).clip_to_rect(&r), Some(s(4.0, 2.0, 4.0, 2.0)));
+    assert_eq!(s(4.0, -1.0, 4.0, 3.0).clip_to_rect(&r), Some(s(4.0, 0.0, 4.0, 2.0)));
+}
+
+#[test]
+fn test_segment_intersect() {
+    let s = |x0, y0, x1, y1| Segment2(Vector2::new(x0, y0), Vector2::new(x1, y1));
+    let a = s(0.0f64, 0.0, 4.0, 4.0);
+    assert_eq!(a.intersect_params(&s(0.0, 4.0, 4.0, 0.0)), Some((0.5, 0.5)));
+    assert_eq!(a.intersect(&s(0.0, 2.0, 4.0, 2.0)), Some(Vector2::new(2.0, 2.0)));
+    assert_eq!(a.intersect(&s(0.0, 1.0, 4.0, 5.0)), None);
+    assert_eq!(a.intersect(&s(3.0, 0.0, 5.0, -2.0)), None);
+    assert_eq!(a.intersect_params(&s(6.0, 6.0, 2.0, 2.0)), Some((0.5, 1.0)));
+    assert_eq!(a.intersect_params(&s(-1.0, -1.0, 1.0, 1.0)), Some((0.0, 0.5)));
+    assert_eq!(a.intersect(&s(5.0, 5.0, 6.0, 6.0)), None);
+    assert_eq!(a.intersect(&s(1.0, 1.0, 1.0, 1.0)), Some(Vector2::new(1.0, 1.0)));
+    assert_eq!(s(1.0, 1.0, 1.0, 1.0).intersect_params(&a), Some((0.0, 0.25)));
+}
+
+#[test]
+fn test_segment_supercover() {
+    let s = |x0: i32, y0, x1, y1| Segment2(Vector2::new(x0, y0), Vector2::new(x1, y1));
+    let v = Vector2::new;
+    assert!(s(2, 1, 2, 1).supercover().eq([v(2, 1)]));
+    assert!(s(0, 0, -2, 0).supercover().eq([v(0, 0), v(-1, 0), v(-2, 0)]));
+    assert!(s(0, 0, 1, 1).supercover().eq([v(0, 0), v(1, 0), v(0, 1), v(1, 1)]));
+    let cells = [v(0, 0), v(1, 0), v(2, 0), v(1, 1), v(2, 1), v(3, 1)];
+    assert!(s(0, 0, 3, 1).supercover().eq(cells));
+    assert!(s(0, 0, 2, 1).supercover().eq([v(0, 0), v(1, 0), v(1, 1), v(2, 1)]));
+    assert_eq!(Segment2(Vector2::new(0u8, 255), Vector2::new(255, 0)).supercover().count(), 766);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_segment_voxels() {
+    let s = Segment3(Vector3::new(0.5f32, 0.5, 0.5), Vector3::new(2.5, 1.5, 0.5));
+    let expected = [
+        (Vector3::new(0, 0, 0), 0.0),
+        (Vector3::new(1, 0, 0), 0.25),
+        (Vector3::new(1, 1, 0), 0.5),
+        (Vector3::new(2, 1, 0), 0.75),
+    ];
+    assert!(s.voxels().eq(expected));
+    let s = Segment3(Vector3::new(-0.5f64, 0.0, 0.0), Vector3::new(-2.5, 0.0, 0.0));
+    assert!(s.voxels().map(|v| v.0.x).eq([-1, -2, -3]));
+    let s = Segment3(Vector3::new(0.2f64, 0.2, 0.2), Vector3::new(0.8, 0.8, 0.8));
+    assert_eq!(s.voxels().count(), 1);
+}
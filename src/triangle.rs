@@ -0,0 +1,317 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::cmp::Ordering;
+use core::ops::{Add, Mul};
+
+use crate::ops::{BoundingRect, ClosestPoint, Contains, Cross, Dot, Orient2d};
+use crate::rect::{Rect2, Rect3};
+use crate::segment::Segment3;
+use crate::vec::{Vector2, Vector3};
+
+/// 2-dimensional triangle structure defined by its three vertices.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Triangle2<T>(pub Vector2<T>, pub Vector2<T>, pub Vector2<T>);
+
+/// 3-dimensional triangle structure defined by its three vertices.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Triangle3<T>(pub Vector3<T>, pub Vector3<T>, pub Vector3<T>);
+
+//--------------------------------------------------------------------------------------------------
+
+/// Implements common traits for triangles.
+macro_rules! impl_all {
+    { $(impl $tri:ident: $rect:ident;)* } => { $(
+        impl<T> $tri<T> {
+            /// Blends a value associated with each vertex, such as a texture coordinate or a
+            /// normal, using barycentric coordinates such as those returned by `barycentric`.
+            pub fn interpolate<V>(bary: Vector3<T>, a: V, b: V, c: V) -> V
+            where V: Add<Output = V> + Mul<T, Output = V>
+            {
+                a * bary.x + b * bary.y + c * bary.z
+            }
+        }
+
+        impl<T> BoundingRect for $tri<T>
+        where T: Clone + PartialOrd
+        {
+            type Output = $rect<T>;
+
+            fn bounding_rect(&self) -> $rect<T> {
+                let min = self.0.clone().min(self.1.clone()).min(self.2.clone());
+                let max = self.0.clone().max(self.1.clone()).max(self.2.clone());
+                $rect(min, max)
+            }
+        }
+    )* };
+}
+
+/// Implements functions for triangles with floating point components.
+macro_rules! impl_float {
+    { $(impl $tri:ident<$t:ident>: $vec:ident;)* } => { $(
+        impl $tri<$t> {
+            /// Returns the barycentric coordinates of `point` with respect to the triangle, so that
+            /// each component weights the corresponding vertex and the components sum to one.
+            /// Points outside the triangle have a negative component. For 3-dimensional
+            /// triangles, `point` is effectively projected onto the triangle's plane. If the
+            /// triangle is degenerate, the result is not finite.
+            pub fn barycentric(&self, point: $vec<$t>) -> Vector3<$t> {
+                let (v0, v1, v2) = (self.1 - self.0, self.2 - self.0, point - self.0);
+                let (d00, d01, d11) = (v0.dot(v0), v0.dot(v1), v1.dot(v1));
+                let (d20, d21) = (v2.dot(v0), v2.dot(v1));
+                let denom = d00 * d11 - d01 * d01;
+                let v = (d11 * d20 - d01 * d21) / denom;
+                let w = (d00 * d21 - d01 * d20) / denom;
+                Vector3::new(1.0 - v - w, v, w)
+            }
+        }
+
+        impl ClosestPoint<$vec<$t>> for $tri<$t> {
+            /// Finds the closest point by classifying `point` against the Voronoi regions of the
+            /// triangle's vertices and edges. For 3-dimensional triangles, a point above the
+            /// interior is projected onto the triangle's plane.
+            fn closest_point(&self, point: &$vec<$t>) -> $vec<$t> {
+                let ($tri(a, b, c), p) = (*self, *point);
+                let (ab, ac) = (b - a, c - a);
+
+                let ap = p - a;
+                let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+                if d1 <= 0.0 && d2 <= 0.0 {
+                    return a;
+                }
+
+                let bp = p - b;
+                let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+                if d3 >= 0.0 && d4 <= d3 {
+                    return b;
+                }
+
+                let vc = d1 * d4 - d3 * d2;
+                if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+                    return a + ab * (d1 / (d1 - d3));
+                }
+
+                let cp = p - c;
+                let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+                if d6 >= 0.0 && d5 <= d6 {
+                    return c;
+                }
+
+                let vb = d5 * d2 - d1 * d6;
+                if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+                    return a + ac * (d2 / (d2 - d6));
+                }
+
+                let va = d3 * d6 - d5 * d4;
+                if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+                    return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+                }
+
+                let denom = va + vb + vc;
+                a + ab * (vb / denom) + ac * (vc / denom)
+            }
+        }
+    )* };
+}
+
+/// Implements [Contains] for 3-dimensional triangles with components of type `$t`, computing the
+/// plane test in `$w`. For integers, `$w` is wide enough that the test is exact.
+macro_rules! impl_contains_3d {
+    { $($t:ident => $w:ident),* } => { $(
+        impl Contains<Vector3<$t>> for Triangle3<$t> {
+            /// Tests whether `rhs` lies in the triangle's plane and inside the triangle or on its
+            /// edges, regardless of the winding order of the triangle's vertices. This is exact for
+            /// integer components, but computed floating point points rarely lie exactly in the
+            /// plane. A degenerate triangle contains the points on its edges.
+            fn contains(&self, rhs: &Vector3<$t>) -> bool {
+                let wide = |v: Vector3<$t>| Vector3::new(v.x as $w, v.y as $w, v.z as $w);
+                let (a, b, c) = (wide(self.0), wide(self.1), wide(self.2));
+                let (normal, zero) = ((b - a).cross(c - a), <$w>::default());
+                if normal == Vector3::default() {
+                    return [(self.0, self.1), (self.1, self.2), (self.2, self.0)]
+                        .into_iter()
+                        .any(|(a, b)| Segment3(a, b).contains(rhs));
+                }
+                if normal.dot(wide(*rhs) - a) != zero {
+                    return false;
+                }
+
+                // Drop an axis along which the normal is nonzero, so the projection keeps the
+                // triangle's shape non-degenerate.
+                let project = |v: Vector3<$t>| if normal.z != zero {
+                    Vector2::new(v.x, v.y)
+                } else if normal.y != zero {
+                    Vector2::new(v.z, v.x)
+                } else {
+                    Vector2::new(v.y, v.z)
+                };
+                let flat = Triangle2(project(self.0), project(self.1), project(self.2));
+                flat.contains(&project(*rhs))
+            }
+        }
+    )* };
+}
+
+impl_all! {
+    impl Triangle2: Rect2;
+    impl Triangle3: Rect3;
+}
+
+impl_float! {
+    impl Triangle2<f32>: Vector2;
+    impl Triangle2<f64>: Vector2;
+    impl Triangle3<f32>: Vector3;
+    impl Triangle3<f64>: Vector3;
+}
+
+impl_contains_3d! {
+    i8 => i128, i16 => i128, i32 => i128, u8 => i128, u16 => i128, u32 => i128,
+    f32 => f32, f64 => f64
+}
+
+impl<T> Contains<Vector2<T>> for Triangle2<T>
+where Vector2<T>: Orient2d
+{
+    /// Tests whether `rhs` lies inside the triangle or on its edges, regardless of the winding
+    /// order of the triangle's vertices. This is exact for integer components.
+    fn contains(&self, rhs: &Vector2<T>) -> bool {
+        let sides = [
+            self.0.orient2d(&self.1, rhs),
+            self.1.orient2d(&self.2, rhs),
+            self.2.orient2d(&self.0, rhs),
+        ];
+        if sides.contains(&None) {
+            return false;
+        }
+        !(sides.contains(&Some(Ordering::Less)) && sides.contains(&Some(Ordering::Greater)))
+    }
+}
+
+impl Triangle2<i32> {
+    /// Iterates over the integer points covered by the triangle, row by row, in order of
+    /// increasing Y and then X. Points on an edge are covered according to the top-left fill rule
+    /// in Y-down coordinates, so triangles which share an edge never both cover a point on it.
+    /// The winding order of the vertices does not matter, and a degenerate triangle covers no
+    /// points.
+    pub fn rasterize(&self) -> impl Iterator<Item = Vector2<i32>> {
+        let v = |p: Vector2<i32>| Vector2::new(p.x as i128, p.y as i128);
+        let (a, mut b, mut c) = (v(self.0), v(self.1), v(self.2));
+        let edge = |a: Vector2<i128>, b: Vector2<i128>, p: Vector2<i128>| {
+            (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+        };
+        let area = edge(a, b, c);
+        if area < 0 {
+            core::mem::swap(&mut b, &mut c);
+        }
+
+        // Points exactly on an edge are only covered if it is a top or left edge.
+        let bias = |a: Vector2<i128>, b: Vector2<i128>| {
+            let top_left = (b.y == a.y && b.x > a.x) || b.y < a.y;
+            if top_left { 0 } else { 1 }
+        };
+        let edges = [(a, b, bias(a, b)), (b, c, bias(b, c)), (c, a, bias(c, a))];
+        let Rect2(min, max) = self.bounding_rect();
+
+        (min.y..=max.y).take_while(move |_| area != 0)
+            .flat_map(move |y| (min.x..=max.x).map(move |x| Vector2::new(x, y)))
+            .filter(move |p| edges.iter().all(|&(a, b, bias)| edge(a, b, v(*p)) >= bias))
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_triangle() {
+    let t = Triangle2(Vector2::new(0, 0), Vector2::new(4, 0), Vector2::new(0, 4));
+    assert!(t.contains(&Vector2::new(1, 1)));
+    assert!(t.contains(&Vector2::new(2, 2)));
+    assert!(!t.contains(&Vector2::new(3, 3)));
+    assert!(Triangle2(t.0, t.2, t.1).contains(&Vector2::new(1, 1)));
+    assert_eq!(t.bounding_rect(), Rect2::new(0, 0, 4, 4));
+
+    let t = Triangle3(Vector3::new(0, 0, 2), Vector3::new(4, 0, 2), Vector3::new(0, 4, 2));
+    assert!(t.contains(&Vector3::new(1, 1, 2)));
+    assert!(t.contains(&Vector3::new(2, 2, 2)));
+    assert!(!t.contains(&Vector3::new(3, 3, 2)));
+    assert!(!t.contains(&Vector3::new(1, 1, 3)));
+    assert!(Triangle3(t.0, t.2, t.1).contains(&Vector3::new(1, 1, 2)));
+    let flat = Triangle3(Vector3::new(0, 0, 0), Vector3::new(2, 2, 2), Vector3::new(4, 4, 4));
+    assert!(flat.contains(&Vector3::new(3, 3, 3)));
+    assert!(!flat.contains(&Vector3::new(3, 3, 2)));
+
+    // Unsigned and extreme components must neither underflow nor overflow.
+    let t = Triangle2(Vector2::new(4u32, 4), Vector2::new(0, 0), Vector2::new(0, 4));
+    assert!(t.contains(&Vector2::new(1, 2)));
+    assert!(!t.contains(&Vector2::new(3, 1)));
+    let (lo, hi) = (i32::MIN, i32::MAX);
+    let t = Triangle2(Vector2::new(lo, lo), Vector2::new(hi, lo), Vector2::new(lo, hi));
+    assert!(t.contains(&Vector2::new(-1, -1)));
+    assert!(!t.contains(&Vector2::new(hi, hi)));
+    let t = Triangle3(Vector3::new(8u32, 0, 5), Vector3::new(0, 8, 5), Vector3::new(0, 0, 5));
+    assert!(t.contains(&Vector3::new(1, 2, 5)));
+    assert!(!t.contains(&Vector3::new(1, 2, 4)));
+    let t = Triangle3(Vector3::new(lo, lo, lo), Vector3::new(hi, lo, hi), Vector3::new(lo, hi, hi));
+    assert!(t.contains(&Vector3::new(lo, lo, lo)));
+    assert!(t.contains(&Vector3::new(lo + 1, lo + 1, lo + 2)));
+    assert!(!t.contains(&Vector3::new(lo + 1, lo + 1, lo + 1)));
+    assert!(!t.contains(&Vector3::new(0, 0, 0)));
+}
+
+#[test]
+fn test_triangle_rasterize() {
+    let t = Triangle2(Vector2::new(0, 0), Vector2::new(3, 0), Vector2::new(0, 3));
+    let v = Vector2::new;
+    let expected = [v(0, 0), v(1, 0), v(2, 0), v(0, 1), v(1, 1), v(0, 2)];
+    assert!(t.rasterize().eq(expected));
+    assert!(Triangle2(t.0, t.2, t.1).rasterize().eq(expected));
+
+    // Two triangles sharing an edge cover each point of the square exactly once.
+    let square = [(0, 0), (4, 0), (4, 4), (0, 4)].map(|(x, y)| v(x, y));
+    let n1 = Triangle2(square[0], square[1], square[2]).rasterize().count();
+    let n2 = Triangle2(square[0], square[2], square[3]).rasterize().count();
+    assert_eq!(n1 + n2, 16);
+    assert_eq!(Triangle2(v(0, 0), v(2, 2), v(4, 4)).rasterize().count(), 0);
+
+    // Edge functions of extreme vertices need more than 64 bits.
+    let t = Triangle2(v(i32::MIN, i32::MIN), v(i32::MAX, i32::MIN), v(i32::MIN, i32::MAX));
+    assert_eq!(t.rasterize().next(), Some(v(i32::MIN, i32::MIN)));
+}
+
+#[test]
+fn test_triangle_barycentric() {
+    let t = Triangle2(Vector2::new(0.0f64, 0.0), Vector2::new(4.0, 0.0), Vector2::new(0.0, 4.0));
+    assert_eq!(t.barycentric(t.1), Vector3::new(0.0, 1.0, 0.0));
+    let bary = t.barycentric(Vector2::new(1.0, 2.0));
+    assert_eq!(bary, Vector3::new(0.25, 0.25, 0.5));
+    assert_eq!(Triangle2::interpolate(bary, t.0, t.1, t.2), Vector2::new(1.0, 2.0));
+    assert_eq!(Triangle2::<f64>::interpolate(bary, 8.0, 4.0, 0.0), 3.0);
+    assert!(t.barycentric(Vector2::new(3.0, 3.0)).x < 0.0);
+
+    let t = Triangle3(Vector3::new(0.0f32, 0.0, 1.0), Vector3::new(2.0, 0.0, 1.0),
+                      Vector3::new(0.0, 2.0, 1.0));
+    assert_eq!(t.barycentric(Vector3::new(1.0, 1.0, 5.0)), Vector3::new(0.0, 0.5, 0.5));
+}
+
+#[test]
+fn test_triangle_closest_point() {
+    let t = Triangle2(Vector2::new(0.0f32, 0.0), Vector2::new(4.0, 0.0), Vector2::new(0.0, 4.0));
+    assert_eq!(t.closest_point(&Vector2::new(1.0, 1.0)), Vector2::new(1.0, 1.0));
+    assert_eq!(t.closest_point(&Vector2::new(-1.0, -1.0)), Vector2::new(0.0, 0.0));
+    assert_eq!(t.closest_point(&Vector2::new(2.0, -3.0)), Vector2::new(2.0, 0.0));
+    assert_eq!(t.closest_point(&Vector2::new(3.0, 3.0)), Vector2::new(2.0, 2.0));
+    assert_eq!(t.closest_point(&Vector2::new(-2.0, 6.0)), Vector2::new(0.0, 4.0));
+
+    let t = Triangle3(Vector3::new(0.0f64, 0.0, 0.0), Vector3::new(4.0, 0.0, 0.0),
+                      Vector3::new(0.0, 4.0, 0.0));
+    assert_eq!(t.closest_point(&Vector3::new(1.0, 1.0, 5.0)), Vector3::new(1.0, 1.0, 0.0));
+    assert_eq!(Rect2::new(0, 0, 4, 4).closest_point(&Vector2::new(6, 2)), Vector2::new(4, 2));
+    let s = crate::Segment2(Vector2::new(0.0f32, 0.0), Vector2::new(2.0, 0.0));
+    assert_eq!(s.closest_point(&Vector2::new(1.0, 1.0)), Vector2::new(1.0, 0.0));
+}
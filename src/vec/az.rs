@@ -0,0 +1,94 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use az::{
+    Cast,
+    CheckedCast,
+    SaturatingCast,
+    WrappingCast,
+};
+
+use crate::vec::{Vector2, Vector3, Vector4};
+
+/// Implements all relevant `az` traits for vector types.
+macro_rules! impl_all {
+    { $(impl $vec:ident($($field:ident),*);)* } => { $(
+        impl<T, U> Cast<$vec<U>> for $vec<T>
+        where T: Cast<U>
+        {
+            fn cast(self) -> $vec<U> {
+                $vec { $($field: self.$field.cast()),* }
+            }
+        }
+
+        impl<T, U> CheckedCast<$vec<U>> for $vec<T>
+        where T: CheckedCast<U>
+        {
+            fn checked_cast(self) -> Option<$vec<U>> {
+                Some($vec { $($field: self.$field.checked_cast()?),* })
+            }
+        }
+
+        impl<T, U> SaturatingCast<$vec<U>> for $vec<T>
+        where T: SaturatingCast<U>
+        {
+            fn saturating_cast(self) -> $vec<U> {
+                $vec { $($field: self.$field.saturating_cast()),* }
+            }
+        }
+
+        impl<T, U> WrappingCast<$vec<U>> for $vec<T>
+        where T: WrappingCast<U>
+        {
+            fn wrapping_cast(self) -> $vec<U> {
+                $vec { $($field: self.$field.wrapping_cast()),* }
+            }
+        }
+    )* };
+}
+
+impl_all! {
+    impl Vector2(x, y);
+    impl Vector3(x, y, z);
+    impl Vector4(x, y, z, w);
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_az_narrowing() {
+    let v = Vector3::new(300i32, -3, 7);
+    assert_eq!(CheckedCast::<Vector3<u8>>::checked_cast(v), None);
+    assert_eq!(CheckedCast::<Vector3<u8>>::checked_cast(Vector3::new(1i32, 2, 3)),
+               Some(Vector3::new(1u8, 2, 3)));
+    assert_eq!(SaturatingCast::<Vector3<u8>>::saturating_cast(v), Vector3::new(255u8, 0, 7));
+    assert_eq!(WrappingCast::<Vector3<u8>>::wrapping_cast(v), Vector3::new(44u8, 253, 7));
+}
+
+#[test]
+fn test_vec_az_widening() {
+    let v = Vector2::new(200u8, 7);
+    assert_eq!(Cast::<Vector2<i32>>::cast(v), Vector2::new(200i32, 7));
+    assert_eq!(CheckedCast::<Vector2<i32>>::checked_cast(v), Some(Vector2::new(200i32, 7)));
+    assert_eq!(SaturatingCast::<Vector2<i32>>::saturating_cast(v), Vector2::new(200i32, 7));
+    assert_eq!(WrappingCast::<Vector2<i32>>::wrapping_cast(v), Vector2::new(200i32, 7));
+}
+
+#[test]
+fn test_vec_az_float_to_int() {
+    let v = Vector4::new(1e10f32, -1e10, 2.5, -2.5);
+    assert_eq!(CheckedCast::<Vector4<i32>>::checked_cast(v), None);
+    assert_eq!(CheckedCast::<Vector4<i32>>::checked_cast(Vector4::new(1.5f32, -1.5, 0.0, 9.0)),
+               Some(Vector4::new(1i32, -1, 0, 9)));
+    assert_eq!(SaturatingCast::<Vector4<i32>>::saturating_cast(v),
+               Vector4::new(i32::MAX, i32::MIN, 2, -2));
+    assert_eq!(WrappingCast::<Vector4<i32>>::wrapping_cast(Vector4::new(256.0f32, -1.0, 3.9, 0.0)),
+               Vector4::new(256i32, -1, 3, 0));
+    assert_eq!(WrappingCast::<Vector2<u8>>::wrapping_cast(Vector2::new(257.0f32, -1.0)),
+               Vector2::new(1u8, 255));
+}
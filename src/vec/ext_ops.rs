@@ -53,23 +53,23 @@ macro_rules! impl_unary_ops {
 macro_rules! impl_scalar_ops {
     { $(impl $trait:ident::$fn:ident for $vec:ident($($field:ident),*);)* } => { $(
         impl<T> $trait<T> for $vec<T>
-        where T: Copy + $trait
+        where T: Clone + $trait
         {
             type Output = $vec<<T as $trait>::Output>;
 
             fn $fn(self, rhs: T) -> Self::Output {
-                $vec { $($field: $trait::$fn(self.$field, rhs)),* }
+                $vec { $($field: $trait::$fn(self.$field, rhs.clone())),* }
             }
         }
 
         impl<'a, T> $trait<T> for &'a $vec<T>
-        where T: Copy,
+        where T: Clone,
               &'a T: $trait<T>
         {
             type Output = $vec<<&'a T as $trait<T>>::Output>;
 
             fn $fn(self, rhs: T) -> Self::Output {
-                $vec { $($field: $trait::$fn(&self.$field, rhs)),* }
+                $vec { $($field: $trait::$fn(&self.$field, rhs.clone())),* }
             }
         }
 
@@ -171,25 +171,25 @@ macro_rules! impl_try_unary_ops {
 macro_rules! impl_try_scalar_ops {
     { $(impl $trait:ident::$fn:ident for $vec:ident($($field:ident),*);)* } => { $(
         impl<T> $trait<T> for $vec<T>
-        where T: Copy + $trait
+        where T: Clone + $trait
         {
             type Output = $vec<<T as $trait>::Output>;
             type Error = <T as $trait>::Error;
 
             fn $fn(self, rhs: T) -> Result<Self::Output, Self::Error> {
-                Ok($vec { $($field: $trait::$fn(self.$field, rhs)?),* })
+                Ok($vec { $($field: $trait::$fn(self.$field, rhs.clone())?),* })
             }
         }
 
         impl<'a, T> $trait<T> for &'a $vec<T>
-        where T: Copy,
+        where T: Clone,
               &'a T: $trait<T>
         {
             type Output = $vec<<&'a T as $trait<T>>::Output>;
             type Error = <&'a T as $trait<T>>::Error;
 
             fn $fn(self, rhs: T) -> Result<Self::Output, Self::Error> {
-                Ok($vec { $($field: $trait::$fn(&self.$field, rhs)?),* })
+                Ok($vec { $($field: $trait::$fn(&self.$field, rhs.clone())?),* })
             }
         }
 
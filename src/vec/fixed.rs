@@ -0,0 +1,112 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use fixed::traits::{
+    Fixed,
+    FromFixed,
+    ToFixed,
+};
+
+use crate::vec::{Vector2, Vector3, Vector4};
+
+/// Implements all relevant `fixed` functionality for vector types.
+macro_rules! impl_all {
+    { $(impl $vec:ident($($field:ident),*);)* } => { $(
+        impl<T: Fixed> $vec<T> {
+            /// Converts a vector of numbers to a vector of fixed-point numbers, returning `None`
+            /// if any component overflows.
+            pub fn checked_from_num<Src: ToFixed>(src: $vec<Src>) -> Option<$vec<T>> {
+                Some($vec { $($field: T::checked_from_num(src.$field)?),* })
+            }
+
+            /// Converts a vector of fixed-point numbers to a vector of numbers, returning `None`
+            /// if any component overflows.
+            pub fn checked_to_num<Dst: FromFixed>(self) -> Option<$vec<Dst>> {
+                Some($vec { $($field: self.$field.checked_to_num()?),* })
+            }
+
+            /// Converts a vector of numbers to a vector of fixed-point numbers.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any component overflows in debug mode, or if a floating point component
+            /// is not finite.
+            pub fn from_num<Src: ToFixed>(src: $vec<Src>) -> $vec<T> {
+                $vec { $($field: T::from_num(src.$field)),* }
+            }
+
+            /// Converts a vector of numbers to a vector of fixed-point numbers, saturating any
+            /// components which overflow.
+            pub fn saturating_from_num<Src: ToFixed>(src: $vec<Src>) -> $vec<T> {
+                $vec { $($field: T::saturating_from_num(src.$field)),* }
+            }
+
+            /// Converts a vector of fixed-point numbers to a vector of numbers, saturating any
+            /// components which overflow.
+            pub fn saturating_to_num<Dst: FromFixed>(self) -> $vec<Dst> {
+                $vec { $($field: self.$field.saturating_to_num()),* }
+            }
+
+            /// Converts a vector of fixed-point numbers to a vector of numbers.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any component overflows in debug mode.
+            pub fn to_num<Dst: FromFixed>(self) -> $vec<Dst> {
+                $vec { $($field: self.$field.to_num()),* }
+            }
+
+            /// Converts a vector of numbers to a vector of fixed-point numbers, wrapping any
+            /// components which overflow.
+            pub fn wrapping_from_num<Src: ToFixed>(src: $vec<Src>) -> $vec<T> {
+                $vec { $($field: T::wrapping_from_num(src.$field)),* }
+            }
+
+            /// Converts a vector of fixed-point numbers to a vector of numbers, wrapping any
+            /// components which overflow.
+            pub fn wrapping_to_num<Dst: FromFixed>(self) -> $vec<Dst> {
+                $vec { $($field: self.$field.wrapping_to_num()),* }
+            }
+        }
+    )* };
+}
+
+impl_all! {
+    impl Vector2(x, y);
+    impl Vector3(x, y, z);
+    impl Vector4(x, y, z, w);
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_fixed_vec_ops() {
+    use fixed::types::I16F16;
+
+    let a = Vector2::<I16F16>::from_num(Vector2::new(1.5f32, -2.25));
+    let b = Vector2::<I16F16>::from_num(Vector2::new(2, 4));
+    assert_eq!((a + b).to_num::<f32>(), Vector2::new(3.5, 1.75));
+    assert_eq!((a * b).to_num::<f32>(), Vector2::new(3.0, -9.0));
+    assert_eq!((b / I16F16::from_num(4)).to_num::<f32>(), Vector2::new(0.5, 1.0));
+    assert_eq!((-a).to_num::<f32>(), Vector2::new(-1.5, 2.25));
+    assert_eq!(Vector2::<I16F16>::checked_from_num(Vector2::new(40000, 0)), None);
+    assert_eq!(Vector2::<I16F16>::saturating_from_num(Vector2::new(40000, 0)).x, I16F16::MAX);
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn test_fixed_vec_num_traits() {
+    use fixed::types::I16F16;
+    use num_traits::{CheckedMul, One, Zero};
+
+    let big = Vector2::<I16F16>::from_num(Vector2::new(30000, 1));
+    assert!(Vector2::<I16F16>::zero().is_zero());
+    assert_eq!(Vector2::<I16F16>::one().to_num::<i32>(), Vector2::new(1, 1));
+    assert_eq!(CheckedMul::checked_mul(&big, &big), None);
+    assert_eq!(CheckedMul::checked_mul(&big, &Vector2::one()), Some(big));
+}
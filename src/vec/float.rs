@@ -0,0 +1,584 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::cmp::Ordering;
+
+use crate::ops::{L1, LInf, Norm, Orient2d};
+#[cfg(feature = "std")]
+use crate::convert::{RoundingMode, SaturatingFrom};
+#[cfg(feature = "std")]
+use crate::ops::{Cross, Dot, InnerSpace, L2, MetricSpace};
+use crate::vec::{Vector2, Vector3, Vector4};
+
+/// Implements functions for vectors with floating point components. Functions which depend on
+/// the platform's math library are only available with the `std` feature.
+macro_rules! impl_float {
+    { $($t:ident),* } => { $(
+        impl_float!(@impl $t: Vector2(x, y), Vector3(x, y, z), Vector4(x, y, z, w));
+    )* };
+    { @impl $t:ident: $($vec:ident($($field:ident),*)),* } => { $(
+        impl $vec<$t> {
+            /// Returns the absolute value of each component.
+            pub fn abs(self) -> $vec<$t> {
+                $vec { $($field: self.$field.abs()),* }
+            }
+
+            /// Returns the smallest integer greater than or equal to each component.
+            #[cfg(feature = "std")]
+            pub fn ceil(self) -> $vec<$t> {
+                $vec { $($field: self.$field.ceil()),* }
+            }
+
+            /// Returns the centroid (mean) of a set of points, accumulated with
+            /// [sum_compensated](Self::sum_compensated). Returns `None` if `points` is empty.
+            pub fn centroid(points: &[$vec<$t>]) -> Option<$vec<$t>> {
+                if points.is_empty() {
+                    return None;
+                }
+                let sum = $vec::<$t>::sum_compensated(points.iter().copied());
+                Some(sum / points.len() as $t)
+            }
+
+            /// Returns the Chebyshev (L∞) length of the vector, which is the greatest absolute
+            /// value of its components.
+            pub fn chebyshev_length(self) -> $t {
+                self.abs().max_element()
+            }
+
+            /// Rounds each component to an integer according to `mode`, then converts the vector
+            /// to another numeric type. Values which are out of range for the target type are
+            /// clamped to its bounds, and `NaN` is converted to zero for integer types.
+            #[cfg(feature = "std")]
+            pub fn convert_rounded<U>(self, mode: RoundingMode) -> $vec<U>
+            where U: SaturatingFrom<$t>
+            {
+                let v = match mode {
+                    RoundingMode::Ceil => self.ceil(),
+                    RoundingMode::Floor => self.floor(),
+                    RoundingMode::Round => self.round(),
+                    RoundingMode::Trunc => self.trunc(),
+                };
+                v.saturating_convert()
+            }
+
+            /// Returns a vector with the magnitude of each component in `self` and the sign of
+            /// the corresponding component in `sign`.
+            pub fn copysign(self, sign: $vec<$t>) -> $vec<$t> {
+                $vec { $($field: self.$field.copysign(sign.$field)),* }
+            }
+
+            /// Performs Euclidean division of each component of `self` by the corresponding
+            /// component of `rhs`.
+            #[cfg(feature = "std")]
+            pub fn div_euclid(self, rhs: $vec<$t>) -> $vec<$t> {
+                $vec { $($field: self.$field.div_euclid(rhs.$field)),* }
+            }
+
+            /// Performs Euclidean division of each component of `self` by `rhs`.
+            #[cfg(feature = "std")]
+            pub fn div_euclid_scalar(self, rhs: $t) -> $vec<$t> {
+                $vec { $($field: self.$field.div_euclid(rhs)),* }
+            }
+
+            /// Computes the dot product of `self` and `rhs` using fused multiply-add operations,
+            /// which round only once per component. This is more precise than [Dot] and may be
+            /// faster on targets with hardware FMA support, but much slower on targets without it.
+            ///
+            /// [Dot]: crate::Dot
+            #[cfg(feature = "std")]
+            pub fn dot_fma(self, rhs: $vec<$t>) -> $t {
+                let mut a = None;
+                $(a = match a {
+                    None => Some(self.$field * rhs.$field),
+                    Some(a) => Some(self.$field.mul_add(rhs.$field, a)),
+                };)*
+                a.unwrap()
+            }
+
+            /// Returns the largest integer less than or equal to each component.
+            #[cfg(feature = "std")]
+            pub fn floor(self) -> $vec<$t> {
+                $vec { $($field: self.$field.floor()),* }
+            }
+
+            /// Returns the fractional part of each component.
+            #[cfg(feature = "std")]
+            pub fn fract(self) -> $vec<$t> {
+                $vec { $($field: self.$field.fract()),* }
+            }
+
+            /// Returns the squared Euclidean length of the vector, computed with
+            /// [dot_fma](Self::dot_fma).
+            #[cfg(feature = "std")]
+            pub fn length_squared_fma(self) -> $t {
+                self.dot_fma(self)
+            }
+
+            /// Linearly interpolates between `self` and `rhs` by `t` using fused multiply-add
+            /// operations. Returns `self` when `t == 0.0` and `rhs` when `t == 1.0`, up to
+            /// rounding.
+            #[cfg(feature = "std")]
+            pub fn lerp_fma(self, rhs: $vec<$t>, t: $t) -> $vec<$t> {
+                $vec { $($field: (rhs.$field - self.$field).mul_add(t, self.$field)),* }
+            }
+
+            /// Returns the Lp distance between `self` and `rhs`.
+            #[cfg(feature = "std")]
+            pub fn lp_distance(self, rhs: $vec<$t>, p: $t) -> $t {
+                (self - rhs).lp_norm(p)
+            }
+
+            /// Returns the Lp norm of the vector, which is the `p`th root of the sum of the
+            /// absolute values of its components raised to the `p`th power. For `p == 1.0` this
+            /// is the Manhattan length, and for `p == 2.0` it is the Euclidean length.
+            #[cfg(feature = "std")]
+            pub fn lp_norm(self, p: $t) -> $t {
+                $vec { $($field: self.$field.abs().powf(p)),* }.sum().powf(p.recip())
+            }
+
+            /// Returns the Manhattan (L1) length of the vector, which is the sum of the absolute
+            /// values of its components.
+            pub fn manhattan_length(self) -> $t {
+                self.abs().sum()
+            }
+
+            /// Returns the componentwise maximum of `self` and `rhs`. If one of a pair of
+            /// components is `NaN`, the other component is returned.
+            pub fn max_ignore_nan(self, rhs: $vec<$t>) -> $vec<$t> {
+                $vec { $($field: self.$field.max(rhs.$field)),* }
+            }
+
+            /// Returns the componentwise maximum of `self` and `rhs`. If either of a pair of
+            /// components is `NaN`, the result for that component is `NaN`.
+            pub fn max_propagate_nan(self, rhs: $vec<$t>) -> $vec<$t> {
+                $vec { $($field: if self.$field.is_nan() || rhs.$field.is_nan() {
+                    $t::NAN
+                } else {
+                    self.$field.max(rhs.$field)
+                }),* }
+            }
+
+            /// Returns the componentwise minimum of `self` and `rhs`. If one of a pair of
+            /// components is `NaN`, the other component is returned.
+            pub fn min_ignore_nan(self, rhs: $vec<$t>) -> $vec<$t> {
+                $vec { $($field: self.$field.min(rhs.$field)),* }
+            }
+
+            /// Returns the componentwise minimum of `self` and `rhs`. If either of a pair of
+            /// components is `NaN`, the result for that component is `NaN`.
+            pub fn min_propagate_nan(self, rhs: $vec<$t>) -> $vec<$t> {
+                $vec { $($field: if self.$field.is_nan() || rhs.$field.is_nan() {
+                    $t::NAN
+                } else {
+                    self.$field.min(rhs.$field)
+                }),* }
+            }
+
+            /// Computes `self * a + b` for each component with a fused multiply-add operation.
+            #[cfg(feature = "std")]
+            pub fn mul_add(self, a: $vec<$t>, b: $vec<$t>) -> $vec<$t> {
+                $vec { $($field: self.$field.mul_add(a.$field, b.$field)),* }
+            }
+
+            /// Returns the least non-negative remainder of each component of `self` divided by the
+            /// corresponding component of `rhs`.
+            #[cfg(feature = "std")]
+            pub fn rem_euclid(self, rhs: $vec<$t>) -> $vec<$t> {
+                $vec { $($field: self.$field.rem_euclid(rhs.$field)),* }
+            }
+
+            /// Returns the least non-negative remainder of each component of `self` divided by
+            /// `rhs`.
+            #[cfg(feature = "std")]
+            pub fn rem_euclid_scalar(self, rhs: $t) -> $vec<$t> {
+                $vec { $($field: self.$field.rem_euclid(rhs)),* }
+            }
+
+            /// Rounds each component to the nearest integer, rounding half-way cases away from
+            /// zero.
+            #[cfg(feature = "std")]
+            pub fn round(self) -> $vec<$t> {
+                $vec { $($field: self.$field.round()),* }
+            }
+
+            /// Returns a vector containing the sign of each component: `1.0` if the component is
+            /// positive, `+0.0` or `INFINITY`, `-1.0` if it is negative, `-0.0` or `NEG_INFINITY`,
+            /// or `NAN` if it is `NAN`.
+            pub fn signum(self) -> $vec<$t> {
+                $vec { $($field: self.$field.signum()),* }
+            }
+
+            /// Snaps each component to a multiple of the corresponding component of `step`,
+            /// choosing between neighboring multiples according to `mode`. The components of
+            /// `step` are expected to be positive.
+            #[cfg(feature = "std")]
+            pub fn snap_to(self, step: $vec<$t>, mode: RoundingMode) -> $vec<$t> {
+                let v = $vec { $($field: self.$field / step.$field),* };
+                let v = match mode {
+                    RoundingMode::Ceil => v.ceil(),
+                    RoundingMode::Floor => v.floor(),
+                    RoundingMode::Round => v.round(),
+                    RoundingMode::Trunc => v.trunc(),
+                };
+                $vec { $($field: v.$field * step.$field),* }
+            }
+
+            /// Sums a sequence of vectors using Neumaier's variant of Kahan summation, which tracks
+            /// the rounding error of each component and is far more precise than naive
+            /// accumulation when summing many values. Returns zero if the sequence is empty.
+            pub fn sum_compensated<I>(iter: I) -> $vec<$t>
+            where I: IntoIterator<Item = $vec<$t>>
+            {
+                let mut sum = $vec { $($field: 0.0),* };
+                let mut c = sum;
+                for v in iter {
+                    $(let t = sum.$field + v.$field;
+                    if sum.$field.abs() >= v.$field.abs() {
+                        c.$field += (sum.$field - t) + v.$field;
+                    } else {
+                        c.$field += (v.$field - t) + sum.$field;
+                    }
+                    sum.$field = t;)*
+                }
+                sum + c
+            }
+
+            /// Compares `self` and `other` lexicographically using the total ordering of each
+            /// component as defined by [`total_cmp`](f32::total_cmp).
+            pub fn total_cmp(&self, other: &$vec<$t>) -> Ordering {
+                Ordering::Equal$(.then_with(|| self.$field.total_cmp(&other.$field)))*
+            }
+
+            /// Returns the integer part of each component.
+            #[cfg(feature = "std")]
+            pub fn trunc(self) -> $vec<$t> {
+                $vec { $($field: self.$field.trunc()),* }
+            }
+
+            /// Returns the weighted centroid of a set of points, where each point is paired with
+            /// its weight. Returns `None` if `points` is empty or if the total weight is zero.
+            pub fn weighted_centroid(points: &[($vec<$t>, $t)]) -> Option<$vec<$t>> {
+                let sum = $vec::<$t>::sum_compensated(points.iter().map(|&(p, w)| p * w));
+                let (mut weight, mut c): ($t, $t) = (0.0, 0.0);
+                for &(_, w) in points {
+                    let t = weight + w;
+                    if weight.abs() >= w.abs() {
+                        c += (weight - t) + w;
+                    } else {
+                        c += (w - t) + weight;
+                    }
+                    weight = t;
+                }
+                let weight = weight + c;
+                if weight == 0.0 {
+                    return None;
+                }
+                Some(sum / weight)
+            }
+        }
+
+        impl Norm<L1> for $vec<$t> {
+            type Output = $t;
+
+            fn norm(self, _: L1) -> $t {
+                self.manhattan_length()
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl Norm<L2> for $vec<$t> {
+            type Output = $t;
+
+            fn norm(self, _: L2) -> $t {
+                self.magnitude_squared().sqrt()
+            }
+        }
+
+        impl Norm<LInf> for $vec<$t> {
+            type Output = $t;
+
+            fn norm(self, _: LInf) -> $t {
+                self.chebyshev_length()
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl MetricSpace for $vec<$t> {
+            type Metric = $t;
+
+            fn distance(self, other: $vec<$t>) -> $t {
+                (self - other).norm(L2)
+            }
+
+            fn distance_squared(self, other: $vec<$t>) -> $t {
+                (self - other).magnitude_squared()
+            }
+        }
+    )* };
+}
+
+/// Implements functions for 2-dimensional vectors with floating point components.
+#[cfg(feature = "std")]
+macro_rules! impl_float_vec2 {
+    { $($t:ident),* } => { $(
+        impl Vector2<$t> {
+            /// Constructs a unit vector pointing in the direction of `theta`, measured in radians
+            /// counter-clockwise from the positive x axis.
+            pub fn from_angle(theta: $t) -> Vector2<$t> {
+                let (sin, cos) = theta.sin_cos();
+                Vector2 { x: cos, y: sin }
+            }
+
+            /// Returns the vector rotated counter-clockwise by `theta` radians.
+            pub fn rotated(self, theta: $t) -> Vector2<$t> {
+                let (sin, cos) = theta.sin_cos();
+                Vector2 {
+                    x: self.x * cos - self.y * sin,
+                    y: self.x * sin + self.y * cos,
+                }
+            }
+
+            /// Returns the angle of the vector in radians, measured counter-clockwise from the
+            /// positive x axis. The result is in the range `[-pi, pi]`.
+            pub fn to_angle(self) -> $t {
+                self.y.atan2(self.x)
+            }
+        }
+    )* };
+}
+
+/// Implements functions for 3-dimensional vectors with floating point components.
+macro_rules! impl_float_vec3 {
+    { $($t:ident),* } => { $(
+        impl Vector3<$t> {
+            /// Returns a vector which is perpendicular to `self`. The result is not normalized,
+            /// but is never zero unless `self` is zero.
+            pub fn any_orthogonal(self) -> Vector3<$t> {
+                if self.x.abs() > self.z.abs() {
+                    Vector3 { x: -self.y, y: self.x, z: 0.0 }
+                } else {
+                    Vector3 { x: 0.0, y: -self.z, z: self.y }
+                }
+            }
+
+            /// Returns two unit vectors which, together with `self`, form a right-handed
+            /// orthonormal basis. `self` must be a unit vector. This uses the branchless method
+            /// described by Duff et al. in "Building an Orthonormal Basis, Revisited".
+            pub fn orthonormal_basis(self) -> (Vector3<$t>, Vector3<$t>) {
+                let sign = (1.0 as $t).copysign(self.z);
+                let a = -1.0 / (sign + self.z);
+                let b = self.x * self.y * a;
+                (
+                    Vector3 { x: 1.0 + sign * self.x * self.x * a, y: sign * b, z: -sign * self.x },
+                    Vector3 { x: b, y: sign + self.y * self.y * a, z: -self.y },
+                )
+            }
+        }
+    )* };
+}
+
+/// Implements functions for 3-dimensional vectors with floating point components which depend on
+/// the platform's math library.
+#[cfg(feature = "std")]
+macro_rules! impl_float_vec3_std {
+    { $($t:ident),* } => { $(
+        impl Vector3<$t> {
+            /// Builds the orientation of an observer looking along `forward` in a left-handed
+            /// coordinate system, where the observer looks down the positive z axis. Returns the
+            /// observer's local x (right), y (up) and z axes expressed in world space, which are
+            /// also the columns of the corresponding rotation matrix. Returns `None` if `forward`
+            /// is zero or parallel to `up`.
+            pub fn look_at_lh(forward: Vector3<$t>, up: Vector3<$t>)
+                -> Option<[Vector3<$t>; 3]>
+            {
+                let z = forward.unit()?;
+                let x = up.cross(z).unit()?;
+                Some([x, z.cross(x), z])
+            }
+
+            /// Builds the orientation of an observer looking along `forward` in a right-handed
+            /// coordinate system, where the observer looks down the negative z axis. Returns the
+            /// observer's local x (right), y (up) and z axes expressed in world space, which are
+            /// also the columns of the corresponding rotation matrix. Returns `None` if `forward`
+            /// is zero or parallel to `up`.
+            pub fn look_at_rh(forward: Vector3<$t>, up: Vector3<$t>)
+                -> Option<[Vector3<$t>; 3]>
+            {
+                let f = forward.unit()?;
+                let x = f.cross(up).unit()?;
+                Some([x, x.cross(f), -f])
+            }
+
+            /// Returns `self` scaled to unit length, or `None` if it is zero or not finite.
+            fn unit(self) -> Option<Vector3<$t>> {
+                let len = self.dot(self).sqrt();
+                if len > 0.0 && len.is_finite() {
+                    Some(self / len)
+                } else {
+                    None
+                }
+            }
+        }
+    )* };
+}
+
+/// Implements [Orient2d] for floating point vectors.
+macro_rules! impl_orient2d {
+    { $($t:ident),* } => { $(
+        impl Orient2d for Vector2<$t> {
+            /// Computes the orientation from the sign of the determinant in floating point. This
+            /// is not robust: when the points are nearly collinear, rounding error may yield
+            /// `Equal` or the wrong sign. Callers which need a tolerance should compare the
+            /// magnitude of the determinant against a threshold scaled to their coordinates, and
+            /// callers which need exact results should snap the points to an integer grid and
+            /// use an integer vector type, whose implementation is exact.
+            fn orient2d(&self, b: &Vector2<$t>, c: &Vector2<$t>) -> Option<Ordering> {
+                let det = (b.x - self.x) * (c.y - self.y) - (b.y - self.y) * (c.x - self.x);
+                det.partial_cmp(&0.0)
+            }
+        }
+    )* };
+}
+
+impl_float!(f32, f64);
+impl_float_vec3!(f32, f64);
+impl_orient2d!(f32, f64);
+
+#[cfg(feature = "std")]
+impl_float_vec3_std!(f32, f64);
+
+#[cfg(feature = "std")]
+impl_float_vec2!(f32, f64);
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_orient2d_float() {
+    use crate::ops::orient2d;
+    let (a, b) = (Vector2::new(0.0f64, 0.0), Vector2::new(1.0, 1.0));
+    assert_eq!(orient2d(&a, &b, &Vector2::new(0.0, 1.0)), Some(Ordering::Greater));
+    assert_eq!(orient2d(&a, &b, &Vector2::new(2.0, 2.0)), Some(Ordering::Equal));
+    assert_eq!(orient2d(&a, &b, &Vector2::new(f64::NAN, 0.0)), None);
+}
+
+#[test]
+fn test_vec_sum_compensated() {
+    let v = [Vector2::new(1.0f32, 1e8), Vector2::new(1e8, 1.0), Vector2::new(-1e8, -1e8)];
+    assert_eq!(Vector2::<f32>::sum_compensated(v), Vector2::new(1.0, 1.0));
+
+    let sum = Vector3::<f32>::sum_compensated((0..10000).map(|_| Vector3::new(0.1f32, 0.2, 0.3)));
+    assert_eq!(sum, Vector3::new(1000.0, 2000.0, 3000.0));
+}
+
+#[test]
+fn test_vec_centroid() {
+    let points = [Vector2::new(0.0f32, 0.0), Vector2::new(4.0, 0.0), Vector2::new(2.0, 3.0)];
+    assert_eq!(Vector2::<f32>::centroid(&points), Some(Vector2::new(2.0, 1.0)));
+    assert_eq!(Vector2::<f32>::centroid(&[]), None);
+
+    let weighted = [(Vector2::new(0.0f64, 0.0), 3.0), (Vector2::new(4.0, 8.0), 1.0)];
+    assert_eq!(Vector2::<f64>::weighted_centroid(&weighted), Some(Vector2::new(1.0, 2.0)));
+    assert_eq!(Vector2::<f64>::weighted_centroid(&[(Vector2::new(1.0f64, 1.0), 0.0)]), None);
+}
+
+#[test]
+fn test_vec3_orthonormal_basis() {
+    use crate::ops::{Cross, Dot};
+
+    for n in [Vector3::new(0.0f64, 0.0, 1.0), Vector3::new(0.0, 0.0, -1.0),
+              Vector3::new(0.6, 0.0, 0.8), Vector3::new(0.48, -0.6, -0.64)] {
+        let (b1, b2) = n.orthonormal_basis();
+        assert!(n.dot(b1).abs() < 1e-12 && n.dot(b2).abs() < 1e-12 && b1.dot(b2).abs() < 1e-12);
+        assert!((b1.dot(b1) - 1.0).abs() < 1e-12 && (b2.dot(b2) - 1.0).abs() < 1e-12);
+        assert!((b1.cross(b2) - n).chebyshev_length() < 1e-12);
+        assert_eq!(n.any_orthogonal().dot(n), 0.0);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_vec3_look_at() {
+    let up = Vector3::new(0.0f64, 1.0, 0.0);
+    let rh = Vector3::<f64>::look_at_rh(Vector3::new(0.0, 0.0, -2.0), up).unwrap();
+    assert_eq!(rh, [Vector3::new(1.0, 0.0, 0.0), up, Vector3::new(0.0, 0.0, 1.0)]);
+    let lh = Vector3::<f64>::look_at_lh(Vector3::new(0.0, 0.0, 2.0), up).unwrap();
+    assert_eq!(lh, [Vector3::new(1.0, 0.0, 0.0), up, Vector3::new(0.0, 0.0, 1.0)]);
+    assert_eq!(Vector3::<f64>::look_at_rh(up, up), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_vec2_angles() {
+    use core::f64::consts::FRAC_PI_2;
+
+    let v = Vector2::<f64>::from_angle(FRAC_PI_2);
+    assert!(v.x.abs() < 1e-12 && (v.y - 1.0).abs() < 1e-12);
+    assert!((Vector2::new(0.0f64, 2.0).to_angle() - FRAC_PI_2).abs() < 1e-12);
+
+    let r = Vector2::new(1.0f64, 0.0).rotated(FRAC_PI_2);
+    assert!(r.x.abs() < 1e-12 && (r.y - 1.0).abs() < 1e-12);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_vec_snap_to() {
+    let v = Vector2::new(1.3f32, -5.0);
+    let step = Vector2::new(0.5, 4.0);
+    assert_eq!(v.snap_to(step, RoundingMode::Floor), Vector2::new(1.0, -8.0));
+    assert_eq!(v.snap_to(step, RoundingMode::Ceil), Vector2::new(1.5, -4.0));
+    assert_eq!(v.snap_to(step, RoundingMode::Round), Vector2::new(1.5, -4.0));
+}
+
+#[test]
+fn test_vec_total_cmp() {
+    assert_eq!(Vector2::new(-0.0f32, 1.0).total_cmp(&Vector2::new(0.0, 0.0)), Ordering::Less);
+    assert_eq!(Vector2::new(1.0f64, f64::NAN).total_cmp(&Vector2::new(1.0, f64::INFINITY)),
+               Ordering::Greater);
+}
+
+#[test]
+fn test_vec_nan_min_max() {
+    let a = Vector2::new(f32::NAN, 1.0);
+    let b = Vector2::new(2.0, f32::NAN);
+    assert_eq!(a.min_ignore_nan(b), Vector2::new(2.0, 1.0));
+    assert_eq!(a.max_ignore_nan(b), Vector2::new(2.0, 1.0));
+    assert!(a.min_propagate_nan(Vector2::new(0.0, 0.0)).x.is_nan());
+    assert_eq!(a.max_propagate_nan(Vector2::new(0.0, 0.0)).y, 1.0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_vec_spaces() {
+    use crate::ops::VectorSpace;
+
+    fn midpoint<V: VectorSpace<Scalar = f32>>(a: V, b: V) -> V {
+        (a + b) / 2.0
+    }
+
+    assert_eq!(midpoint(Vector2::new(0.0, 2.0), Vector2::new(4.0, 0.0)), Vector2::new(2.0, 1.0));
+    assert_eq!(Vector3::new(1.0f64, 2.0, 2.0).magnitude_squared(), 9.0);
+    assert_eq!(Vector2::new(1.0f32, 1.0).distance(Vector2::new(4.0, 5.0)), 5.0);
+    assert_eq!(Vector4::<i32>::zero(), Vector4::new(0, 0, 0, 0));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_vec_fma() {
+    let (a, b) = (Vector2::new(1.0f64, 0.1), Vector2::new(-1.0, 10.0));
+    assert_eq!(a.dot(b), 0.0);
+    assert_eq!(a.dot_fma(b), 0.1f64.mul_add(10.0, -1.0));
+    assert_ne!(a.dot_fma(b), 0.0);
+
+    let (a, b) = (Vector2::new(0.0f32, 10.0), Vector2::new(10.0, 20.0));
+    assert_eq!(a.lerp_fma(b, 0.25), Vector2::new(2.5, 12.5));
+    assert_eq!(a.lerp_fma(b, 1.0), b);
+
+    let v = Vector3::new(1.0f64, 2.0, 3.0);
+    assert_eq!(v.mul_add(Vector3::new(2.0, 2.0, 2.0), Vector3::new(1.0, 1.0, 1.0)),
+               Vector3::new(3.0, 5.0, 7.0));
+}
@@ -0,0 +1,86 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use half::{bf16, f16};
+
+use crate::vec::{Vector2, Vector3, Vector4};
+
+/// Implements functions for vectors with half-precision floating point components. `$n` is the
+/// size of the vector in bytes.
+macro_rules! impl_half {
+    { $($t:ident),* } => { $(
+        impl_half!(@impl $t: Vector2(x, y; 4), Vector3(x, y, z; 6), Vector4(x, y, z, w; 8));
+    )* };
+    { @impl $t:ident: $($vec:ident($($field:ident),*; $n:expr)),* } => { $(
+        impl $vec<$t> {
+            /// Decodes a vector from the big-endian byte representations of its components.
+            pub fn from_be_bytes(bytes: [u8; $n]) -> $vec<$t> {
+                let mut iter = bytes.chunks_exact(2).map(|b| $t::from_be_bytes([b[0], b[1]]));
+                $vec { $($field: iter.next().unwrap()),* }
+            }
+
+            /// Converts a vector of `f32` values to half precision, rounding as necessary.
+            pub fn from_f32(v: $vec<f32>) -> $vec<$t> {
+                $vec { $($field: $t::from_f32(v.$field)),* }
+            }
+
+            /// Converts a vector of `f64` values to half precision, rounding as necessary.
+            pub fn from_f64(v: $vec<f64>) -> $vec<$t> {
+                $vec { $($field: $t::from_f64(v.$field)),* }
+            }
+
+            /// Decodes a vector from the little-endian byte representations of its components.
+            pub fn from_le_bytes(bytes: [u8; $n]) -> $vec<$t> {
+                let mut iter = bytes.chunks_exact(2).map(|b| $t::from_le_bytes([b[0], b[1]]));
+                $vec { $($field: iter.next().unwrap()),* }
+            }
+
+            /// Encodes the vector as the big-endian byte representations of its components.
+            pub fn to_be_bytes(self) -> [u8; $n] {
+                let mut bytes = [0; $n];
+                for (b, n) in bytes.chunks_exact_mut(2).zip([$(self.$field),*]) {
+                    b.copy_from_slice(&n.to_be_bytes());
+                }
+                bytes
+            }
+
+            /// Converts the vector's components to `f32`.
+            pub fn to_f32(self) -> $vec<f32> {
+                $vec { $($field: self.$field.to_f32()),* }
+            }
+
+            /// Converts the vector's components to `f64`.
+            pub fn to_f64(self) -> $vec<f64> {
+                $vec { $($field: self.$field.to_f64()),* }
+            }
+
+            /// Encodes the vector as the little-endian byte representations of its components.
+            pub fn to_le_bytes(self) -> [u8; $n] {
+                let mut bytes = [0; $n];
+                for (b, n) in bytes.chunks_exact_mut(2).zip([$(self.$field),*]) {
+                    b.copy_from_slice(&n.to_le_bytes());
+                }
+                bytes
+            }
+        }
+    )* };
+}
+
+impl_half!(f16, bf16);
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_half_vec_bytes() {
+    let v = Vector3::<f16>::from_f32(Vector3::new(1.0, -2.0, 0.5));
+    assert_eq!(v.to_le_bytes(), [0x00, 0x3c, 0x00, 0xc0, 0x00, 0x38]);
+    assert_eq!(Vector3::<f16>::from_le_bytes(v.to_le_bytes()), v);
+    assert_eq!(Vector3::<f16>::from_be_bytes(v.to_be_bytes()), v);
+    assert_eq!(v.to_f32(), Vector3::new(1.0, -2.0, 0.5));
+    assert_eq!(v.convert::<f32>(), Vector3::new(1.0, -2.0, 0.5));
+}
@@ -0,0 +1,351 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::cmp::Ordering;
+
+use crate::convert::RoundingMode;
+use crate::ops::{L1, LInf, Norm, Orient2d};
+use crate::vec::{Vector2, Vector3, Vector4};
+
+/// Implements functions for vectors with integer components. `$u` is the unsigned counterpart of
+/// `$t`.
+macro_rules! impl_int {
+    { $($t:ident => $u:ident),* } => { $(
+        impl_int!(@impl $t => $u: Vector2(x, y), Vector3(x, y, z), Vector4(x, y, z, w));
+    )* };
+    { @impl $t:ident => $u:ident: $($vec:ident($($field:ident),*)),* } => { $(
+        impl $vec<$t> {
+            /// Returns the absolute difference between each component of `self` and the
+            /// corresponding component of `rhs`. This never overflows.
+            pub fn abs_diff(self, rhs: $vec<$t>) -> $vec<$u> {
+                $vec { $($field: self.$field.abs_diff(rhs.$field)),* }
+            }
+
+            /// Returns the Chebyshev (L∞) length of the vector, which is the greatest absolute
+            /// value of its components.
+            pub fn chebyshev_length(self) -> $u {
+                $vec { $($field: self.$field.abs_diff(0)),* }.max_element()
+            }
+
+            /// Adds `rhs` to `self`, returning `None` if any component overflows.
+            pub fn checked_add(self, rhs: $vec<$t>) -> Option<$vec<$t>> {
+                Some($vec { $($field: self.$field.checked_add(rhs.$field)?),* })
+            }
+
+            /// Divides `self` by `rhs`, returning `None` if any component of `rhs` is zero or if
+            /// any component overflows.
+            pub fn checked_div(self, rhs: $vec<$t>) -> Option<$vec<$t>> {
+                Some($vec { $($field: self.$field.checked_div(rhs.$field)?),* })
+            }
+
+            /// Multiplies `self` by `rhs`, returning `None` if any component overflows.
+            pub fn checked_mul(self, rhs: $vec<$t>) -> Option<$vec<$t>> {
+                Some($vec { $($field: self.$field.checked_mul(rhs.$field)?),* })
+            }
+
+            /// Negates `self`, returning `None` if any component overflows.
+            pub fn checked_neg(self) -> Option<$vec<$t>> {
+                Some($vec { $($field: self.$field.checked_neg()?),* })
+            }
+
+            /// Computes the remainder of `self` divided by `rhs`, returning `None` if any
+            /// component of `rhs` is zero or if any component overflows.
+            pub fn checked_rem(self, rhs: $vec<$t>) -> Option<$vec<$t>> {
+                Some($vec { $($field: self.$field.checked_rem(rhs.$field)?),* })
+            }
+
+            /// Subtracts `rhs` from `self`, returning `None` if any component overflows.
+            pub fn checked_sub(self, rhs: $vec<$t>) -> Option<$vec<$t>> {
+                Some($vec { $($field: self.$field.checked_sub(rhs.$field)?),* })
+            }
+
+            /// Performs Euclidean division of each component of `self` by the corresponding
+            /// component of `rhs`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any component of `rhs` is zero, or if a division overflows.
+            pub fn div_euclid(self, rhs: $vec<$t>) -> $vec<$t> {
+                $vec { $($field: self.$field.div_euclid(rhs.$field)),* }
+            }
+
+            /// Performs Euclidean division of each component of `self` by `rhs`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `rhs` is zero, or if a division overflows.
+            pub fn div_euclid_scalar(self, rhs: $t) -> $vec<$t> {
+                $vec { $($field: self.$field.div_euclid(rhs)),* }
+            }
+
+            /// Returns the Manhattan (L1) length of the vector, which is the sum of the absolute
+            /// values of its components.
+            pub fn manhattan_length(self) -> $u {
+                $vec { $($field: self.$field.abs_diff(0)),* }.sum()
+            }
+
+            /// Returns the least non-negative remainder of each component of `self` divided by the
+            /// corresponding component of `rhs`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any component of `rhs` is zero, or if a division overflows.
+            pub fn rem_euclid(self, rhs: $vec<$t>) -> $vec<$t> {
+                $vec { $($field: self.$field.rem_euclid(rhs.$field)),* }
+            }
+
+            /// Returns the least non-negative remainder of each component of `self` divided by
+            /// `rhs`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `rhs` is zero, or if a division overflows.
+            pub fn rem_euclid_scalar(self, rhs: $t) -> $vec<$t> {
+                $vec { $($field: self.$field.rem_euclid(rhs)),* }
+            }
+
+            /// Adds `rhs` to `self`, saturating each component at the numeric bounds.
+            pub fn saturating_add(self, rhs: $vec<$t>) -> $vec<$t> {
+                $vec { $($field: self.$field.saturating_add(rhs.$field)),* }
+            }
+
+            /// Multiplies `self` by `rhs`, saturating each component at the numeric bounds.
+            pub fn saturating_mul(self, rhs: $vec<$t>) -> $vec<$t> {
+                $vec { $($field: self.$field.saturating_mul(rhs.$field)),* }
+            }
+
+            /// Subtracts `rhs` from `self`, saturating each component at the numeric bounds.
+            pub fn saturating_sub(self, rhs: $vec<$t>) -> $vec<$t> {
+                $vec { $($field: self.$field.saturating_sub(rhs.$field)),* }
+            }
+
+            /// Snaps each component to a multiple of the corresponding component of `step`,
+            /// choosing between neighboring multiples according to `mode`. The components of
+            /// `step` are expected to be positive.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any component of `step` is zero. The snapped value of a component near
+            /// the numeric bounds may not be representable and will panic in debug mode.
+            #[allow(unused_comparisons)]
+            pub fn snap_to(self, step: $vec<$t>, mode: RoundingMode) -> $vec<$t> {
+                $vec { $($field: {
+                    let (q, r) = (self.$field.div_euclid(step.$field),
+                                  self.$field.rem_euclid(step.$field));
+                    let up = match mode {
+                        RoundingMode::Ceil => r != 0,
+                        RoundingMode::Floor => false,
+                        RoundingMode::Round => r > step.$field - r
+                                               || (r == step.$field - r && q >= 0),
+                        RoundingMode::Trunc => r != 0 && q < 0,
+                    };
+                    if up { (q + 1) * step.$field } else { q * step.$field }
+                }),* }
+            }
+
+            /// Adds `rhs` to `self`, wrapping each component around at the numeric bounds.
+            pub fn wrapping_add(self, rhs: $vec<$t>) -> $vec<$t> {
+                $vec { $($field: self.$field.wrapping_add(rhs.$field)),* }
+            }
+
+            /// Multiplies `self` by `rhs`, wrapping each component around at the numeric bounds.
+            pub fn wrapping_mul(self, rhs: $vec<$t>) -> $vec<$t> {
+                $vec { $($field: self.$field.wrapping_mul(rhs.$field)),* }
+            }
+
+            /// Negates `self`, wrapping each component around at the numeric bounds.
+            pub fn wrapping_neg(self) -> $vec<$t> {
+                $vec { $($field: self.$field.wrapping_neg()),* }
+            }
+
+            /// Subtracts `rhs` from `self`, wrapping each component around at the numeric bounds.
+            pub fn wrapping_sub(self, rhs: $vec<$t>) -> $vec<$t> {
+                $vec { $($field: self.$field.wrapping_sub(rhs.$field)),* }
+            }
+        }
+
+        impl Norm<L1> for $vec<$t> {
+            type Output = $u;
+
+            fn norm(self, _: L1) -> $u {
+                self.manhattan_length()
+            }
+        }
+
+        impl Norm<LInf> for $vec<$t> {
+            type Output = $u;
+
+            fn norm(self, _: LInf) -> $u {
+                self.chebyshev_length()
+            }
+        }
+    )* };
+}
+
+/// Implements functions for integer vectors which accumulate intermediate values in a wider
+/// integer type `$w`.
+macro_rules! impl_int_accumulate {
+    { $($t:ident => $w:ident),* } => { $(
+        impl_int_accumulate!(@impl $t => $w: Vector2(x, y), Vector3(x, y, z), Vector4(x, y, z, w));
+    )* };
+    { @impl $t:ident => $w:ident: $($vec:ident($($field:ident),*)),* } => { $(
+        impl $vec<$t> {
+            /// Returns the centroid (mean) of a set of points, rounded toward negative infinity.
+            /// Returns `None` if `points` is empty or if the sum of the points overflows the
+            /// intermediate type, which can only happen for 128-bit components.
+            pub fn centroid(points: &[$vec<$t>]) -> Option<$vec<$t>> {
+                let n = $w::try_from(points.len()).ok()?;
+                if n == 0 {
+                    return None;
+                }
+                let mut sum: $vec<$w> = $vec { $($field: 0),* };
+                for p in points {
+                    $(sum.$field = sum.$field.checked_add(p.$field as $w)?;)*
+                }
+                Some($vec { $($field: sum.$field.div_euclid(n) as $t),* })
+            }
+        }
+    )* };
+}
+
+/// Implements functions for vectors with signed integer components.
+macro_rules! impl_signed_int {
+    { $($t:ident),* } => { $(
+        impl_signed_int!(@impl $t: Vector2(x, y), Vector3(x, y, z), Vector4(x, y, z, w));
+    )* };
+    { @impl $t:ident: $($vec:ident($($field:ident),*)),* } => { $(
+        impl $vec<$t> {
+            /// Returns the absolute value of each component.
+            ///
+            /// # Overflow behavior
+            ///
+            /// The absolute value of `MIN` cannot be represented and will panic in debug mode.
+            pub fn abs(self) -> $vec<$t> {
+                $vec { $($field: self.$field.abs()),* }
+            }
+
+            /// Negates `self`, saturating each component at the numeric bounds.
+            pub fn saturating_neg(self) -> $vec<$t> {
+                $vec { $($field: self.$field.saturating_neg()),* }
+            }
+
+            /// Returns a vector containing the sign of each component: `0` if the component is
+            /// zero, `1` if it is positive, or `-1` if it is negative.
+            pub fn signum(self) -> $vec<$t> {
+                $vec { $($field: self.$field.signum()),* }
+            }
+        }
+    )* };
+}
+
+impl_int! {
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize,
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize
+}
+
+impl_int_accumulate! {
+    i8 => i128, i16 => i128, i32 => i128, i64 => i128, i128 => i128, isize => i128,
+    u8 => u128, u16 => u128, u32 => u128, u64 => u128, u128 => u128, usize => u128
+}
+
+impl_signed_int!(i8, i16, i32, i64, i128, isize);
+
+/// Implements [Orient2d] for integer vectors which fit in `i128` with room for one more bit.
+macro_rules! impl_orient2d {
+    { $($t:ident),* } => { $(
+        impl Orient2d for Vector2<$t> {
+            /// Computes the orientation exactly, without overflow.
+            fn orient2d(&self, b: &Vector2<$t>, c: &Vector2<$t>) -> Option<Ordering> {
+                let (x, y) = (self.x as i128, self.y as i128);
+                let ((bx, by), (cx, cy)) = ((b.x as i128 - x, b.y as i128 - y),
+                                            (c.x as i128 - x, c.y as i128 - y));
+                Some(cmp_products(bx, cy, by, cx))
+            }
+        }
+    )* };
+}
+
+impl_orient2d!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Compares `a * b` with `c * d` exactly, given that each product's magnitude fits in `u128`.
+fn cmp_products(a: i128, b: i128, c: i128, d: i128) -> Ordering {
+    let sign = |x: i128, y: i128| x.signum() * y.signum();
+    let (s, t) = (sign(a, b), sign(c, d));
+    if s != t {
+        return s.cmp(&t);
+    }
+    let (m, n) = (a.unsigned_abs() * b.unsigned_abs(), c.unsigned_abs() * d.unsigned_abs());
+    if s < 0 { n.cmp(&m) } else { m.cmp(&n) }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_abs_diff() {
+    assert_eq!(Vector2::new(i8::MIN, 5).abs_diff(Vector2::new(i8::MAX, -5)),
+               Vector2::new(255u8, 10));
+    assert_eq!(Vector2::new(3u32, 10).abs_diff(Vector2::new(10, 3)), Vector2::new(7, 7));
+}
+
+#[test]
+fn test_vec_centroid() {
+    let points = [Vector2::new(i32::MAX, -3), Vector2::new(i32::MAX, -4)];
+    assert_eq!(Vector2::<i32>::centroid(&points), Some(Vector2::new(i32::MAX, -4)));
+    assert_eq!(Vector2::<u8>::centroid(&[]), None);
+}
+
+#[test]
+fn test_vec_overflow_ops() {
+    assert_eq!(Vector2::new(250u8, 1).checked_add(Vector2::new(5, 1)), Some(Vector2::new(255, 2)));
+    assert_eq!(Vector2::new(250u8, 1).checked_add(Vector2::new(6, 1)), None);
+    assert_eq!(Vector2::new(250u8, 1).saturating_add(Vector2::new(6, 1)), Vector2::new(255, 2));
+    assert_eq!(Vector2::new(250u8, 1).wrapping_add(Vector2::new(6, 1)), Vector2::new(0, 2));
+    assert_eq!(Vector2::new(i8::MIN, 1).saturating_neg(), Vector2::new(i8::MAX, -1));
+}
+
+#[test]
+fn test_vec_euclid() {
+    assert_eq!(Vector2::new(-1i32, 17).div_euclid_scalar(16), Vector2::new(-1, 1));
+    assert_eq!(Vector2::new(-1i32, 17).rem_euclid_scalar(16), Vector2::new(15, 1));
+    assert_eq!(Vector2::new(-7i32, 7).rem_euclid(Vector2::new(4, -4)), Vector2::new(1, 3));
+}
+
+#[test]
+fn test_vec_snap_to() {
+    let v = Vector3::new(-5i32, 6, 5);
+    let step = Vector3::new(4, 4, 4);
+    assert_eq!(v.snap_to(step, RoundingMode::Floor), Vector3::new(-8, 4, 4));
+    assert_eq!(v.snap_to(step, RoundingMode::Ceil), Vector3::new(-4, 8, 8));
+    assert_eq!(v.snap_to(step, RoundingMode::Trunc), Vector3::new(-4, 4, 4));
+    assert_eq!(Vector3::new(-6i32, 6, 5).snap_to(step, RoundingMode::Round),
+               Vector3::new(-8, 8, 4));
+    assert_eq!(Vector2::new(7u8, 250).snap_to(Vector2::new(5, 5), RoundingMode::Round),
+               Vector2::new(5, 250));
+}
+
+#[test]
+fn test_vec_norm() {
+    assert_eq!(Vector3::new(-3i8, 4, -128).norm(L1), 135u8);
+    assert_eq!(Vector3::new(-3i8, 4, -128).norm(LInf), 128u8);
+}
+
+#[test]
+fn test_vec_orient2d() {
+    use crate::ops::orient2d;
+    let (a, b) = (Vector2::new(0, 0), Vector2::new(4, 0));
+    assert_eq!(orient2d(&a, &b, &Vector2::new(1, 1)), Some(Ordering::Greater));
+    assert_eq!(orient2d(&a, &b, &Vector2::new(1, -1)), Some(Ordering::Less));
+    assert_eq!(orient2d(&a, &b, &Vector2::new(9, 0)), Some(Ordering::Equal));
+    let (a, b, c) = (Vector2::new(i64::MIN, i64::MIN), Vector2::new(i64::MAX, i64::MAX),
+                     Vector2::new(i64::MAX - 1, i64::MAX));
+    assert_eq!(orient2d(&a, &b, &c), Some(Ordering::Greater));
+    assert_eq!(orient2d(&a, &c, &b), Some(Ordering::Less));
+    assert_eq!(orient2d(&a, &b, &Vector2::new(0, 0)), Some(Ordering::Equal));
+    let (a, b) = (Vector2::new(0u64, u64::MAX), Vector2::new(u64::MAX, 0));
+    assert_eq!(orient2d(&a, &b, &Vector2::new(u64::MAX, u64::MAX)), Some(Ordering::Greater));
+}
@@ -6,12 +6,24 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+#[cfg(feature = "az")]
+mod az;
+
 #[cfg(feature = "cgmath")]
 mod cgmath;
 
 #[cfg(feature = "ext-ops")]
 mod ext_ops;
 
+#[cfg(feature = "fixed")]
+mod fixed;
+
+#[cfg(feature = "half")]
+mod half;
+
+mod float;
+mod int;
+
 #[cfg(feature = "num-traits")]
 mod num_traits;
 
@@ -19,16 +31,42 @@ use core::fmt::{Display, Formatter};
 use core::ops::{
     Add,
     AddAssign,
+    BitAnd,
+    BitAndAssign,
+    BitOr,
+    BitOrAssign,
+    BitXor,
+    BitXorAssign,
     Div,
     DivAssign,
+    Index,
+    IndexMut,
     Mul,
     MulAssign,
     Neg,
+    Not,
+    Rem,
+    RemAssign,
+    Shl,
+    ShlAssign,
+    Shr,
+    ShrAssign,
     Sub,
     SubAssign,
 };
 
-use crate::ops::{Cross, Dot};
+use crate::axis::{Axis2, Axis3, Axis4};
+use crate::convert::{SaturatingFrom, WrappingFrom};
+use crate::ops::{
+    ComponentWise,
+    Cross,
+    Dot,
+    InnerSpace,
+    Lerp,
+    Select,
+    VectorN,
+    VectorSpace,
+};
 
 /// 2-dimensional vector type.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
@@ -38,6 +76,31 @@ pub struct Vector2<T> {
     pub y: T,
 }
 
+impl<T> Vector2<T> {
+    /// Rotates the vector by 180 degrees. This is exact for any scalar type.
+    pub fn rotate_180(self) -> Vector2<T>
+    where T: Neg<Output = T>
+    {
+        Vector2 { x: -self.x, y: -self.y }
+    }
+
+    /// Rotates the vector by 90 degrees counter-clockwise, assuming the y axis points up. This
+    /// is exact for any scalar type.
+    pub fn rotate_90_ccw(self) -> Vector2<T>
+    where T: Neg<Output = T>
+    {
+        Vector2 { x: -self.y, y: self.x }
+    }
+
+    /// Rotates the vector by 90 degrees clockwise, assuming the y axis points up. This is exact
+    /// for any scalar type.
+    pub fn rotate_90_cw(self) -> Vector2<T>
+    where T: Neg<Output = T>
+    {
+        Vector2 { x: self.y, y: -self.x }
+    }
+}
+
 impl<T: Display> Display for Vector2<T> {
     fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
         f.write_str("(")?;
@@ -71,23 +134,59 @@ pub struct Vector3<T> {
     pub z: T,
 }
 
+impl<T> Vector3<T> {
+    /// Converts between right-handed and left-handed coordinates by negating the `z` component,
+    /// e.g. between OpenGL (Y-up, right-handed) and Unity (Y-up, left-handed) conventions.
+    pub fn flip_handedness(self) -> Vector3<T>
+    where T: Neg<Output = T>
+    {
+        self.flip_z()
+    }
+
+    /// Computes the scalar triple product `self · (b × c)`, which is the signed volume of the
+    /// parallelepiped defined by the three vectors.
+    pub fn triple(self, b: Vector3<T>, c: Vector3<T>) -> T
+    where T: Add<Output = T> + Copy + Mul<Output = T> + Sub<Output = T>
+    {
+        crate::ops::triple(self, b, c)
+    }
+
+    /// Converts a right-handed Y-up vector (as in OpenGL or glTF) to a right-handed Z-up vector
+    /// (as in Blender), mapping `(x, y, z)` to `(x, -z, y)`. This is the inverse of
+    /// [zup_to_yup](Self::zup_to_yup).
+    pub fn yup_to_zup(self) -> Vector3<T>
+    where T: Neg<Output = T>
+    {
+        Vector3 { x: self.x, y: -self.z, z: self.y }
+    }
+
+    /// Converts a right-handed Z-up vector (as in Blender) to a right-handed Y-up vector (as in
+    /// OpenGL or glTF), mapping `(x, y, z)` to `(x, z, -y)`. This is the inverse of
+    /// [yup_to_zup](Self::yup_to_zup).
+    pub fn zup_to_yup(self) -> Vector3<T>
+    where T: Neg<Output = T>
+    {
+        Vector3 { x: self.x, y: self.z, z: -self.y }
+    }
+}
+
 impl<T> Cross for Vector3<T>
-where T: Copy + Mul,
+where T: Clone + Mul,
       <T as Mul>::Output: Sub
 {
     type Output = Vector3<<<T as Mul>::Output as Sub>::Output>;
 
     fn cross(self, rhs: Vector3<T>) -> Self::Output {
         Vector3 {
-            x: self.y * rhs.z - self.z * rhs.y,
-            y: self.z * rhs.x - self.x * rhs.z,
+            x: self.y.clone() * rhs.z.clone() - self.z.clone() * rhs.y.clone(),
+            y: self.z * rhs.x.clone() - self.x.clone() * rhs.z,
             z: self.x * rhs.y - self.y * rhs.x,
         }
     }
 }
 
 impl<'a, T> Cross<Vector3<T>> for &'a Vector3<T>
-where T: Copy,
+where T: Clone,
       &'a T: Mul<T>,
       <&'a T as Mul<T>>::Output: Sub
 {
@@ -95,23 +194,23 @@ where T: Copy,
 
     fn cross(self, rhs: Vector3<T>) -> Self::Output {
         Vector3 {
-            x: &self.y * rhs.z - &self.z * rhs.y,
-            y: &self.z * rhs.x - &self.x * rhs.z,
+            x: &self.y * rhs.z.clone() - &self.z * rhs.y.clone(),
+            y: &self.z * rhs.x.clone() - &self.x * rhs.z,
             z: &self.x * rhs.y - &self.y * rhs.x,
         }
     }
 }
 
 impl<'r, T> Cross<&'r Vector3<T>> for Vector3<T>
-where T: Copy + Mul<&'r T>,
+where T: Clone + Mul<&'r T>,
       <T as Mul<&'r T>>::Output: Sub
 {
     type Output = Vector3<<<T as Mul<&'r T>>::Output as Sub>::Output>;
 
     fn cross(self, rhs: &'r Vector3<T>) -> Self::Output {
         Vector3 {
-            x: self.y * &rhs.z - self.z * &rhs.y,
-            y: self.z * &rhs.x - self.x * &rhs.z,
+            x: self.y.clone() * &rhs.z - self.z.clone() * &rhs.y,
+            y: self.z * &rhs.x - self.x.clone() * &rhs.z,
             z: self.x * &rhs.y - self.y * &rhs.x,
         }
     }
@@ -214,23 +313,23 @@ macro_rules! impl_unary_ops {
 macro_rules! impl_scalar_ops {
     { $(impl $trait:ident::$fn:ident for $vec:ident($($field:ident),*);)* } => { $(
         impl<T> $trait<T> for $vec<T>
-        where T: Copy + $trait
+        where T: Clone + $trait
         {
             type Output = $vec<<T as $trait>::Output>;
 
             fn $fn(self, rhs: T) -> Self::Output {
-                $vec { $($field: $trait::$fn(self.$field, rhs)),* }
+                $vec { $($field: $trait::$fn(self.$field, rhs.clone())),* }
             }
         }
 
         impl<'a, T> $trait<T> for &'a $vec<T>
-        where T: Copy,
+        where T: Clone,
               &'a T: $trait<T>
         {
             type Output = $vec<<&'a T as $trait<T>>::Output>;
 
             fn $fn(self, rhs: T) -> Self::Output {
-                $vec { $($field: $trait::$fn(&self.$field, rhs)),* }
+                $vec { $($field: $trait::$fn(&self.$field, rhs.clone())),* }
             }
         }
 
@@ -260,10 +359,10 @@ macro_rules! impl_scalar_ops {
 macro_rules! impl_scalar_assign_ops {
     { $(impl $trait:ident::$fn:ident for $vec:ident($($field:ident),*);)* } => { $(
         impl<T> $trait<T> for $vec<T>
-        where T: Copy + $trait
+        where T: Clone + $trait
         {
             fn $fn(&mut self, rhs: T) {
-                $($trait::$fn(&mut self.$field, rhs);)*
+                $($trait::$fn(&mut self.$field, rhs.clone());)*
             }
         }
 
@@ -347,6 +446,71 @@ macro_rules! impl_binary_assign_ops {
 macro_rules! impl_all {
     { $(impl $vec:ident($($field:ident: $t:ident),*; $n:expr);)* } => { $(
         impl<T> $vec<T> {
+            /// Returns the Chebyshev (L∞) distance between `self` and `rhs`, which is the greatest
+            /// absolute difference between corresponding components.
+            pub fn chebyshev_distance(self, rhs: $vec<T>) -> T
+            where T: PartialOrd + Sub<Output = T>
+            {
+                $vec { $($field: diff(self.$field, rhs.$field)),* }.max_element()
+            }
+
+            /// Restricts each component to the range defined by the corresponding components of
+            /// `min` and `max`. If a component of `min` is greater than the corresponding
+            /// component of `max`, the result for that component is `max`.
+            pub fn clamp(self, min: $vec<T>, max: $vec<T>) -> $vec<T>
+            where T: PartialOrd
+            {
+                self.max(min).min(max)
+            }
+
+            /// Returns a mask which is `true` for each component of `self` that is equal to the
+            /// corresponding component of `rhs`.
+            pub fn cmpeq(&self, rhs: &$vec<T>) -> $vec<bool>
+            where T: PartialEq
+            {
+                $vec { $($field: self.$field == rhs.$field),* }
+            }
+
+            /// Returns a mask which is `true` for each component of `self` that is greater than or
+            /// equal to the corresponding component of `rhs`.
+            pub fn cmpge(&self, rhs: &$vec<T>) -> $vec<bool>
+            where T: PartialOrd
+            {
+                $vec { $($field: self.$field >= rhs.$field),* }
+            }
+
+            /// Returns a mask which is `true` for each component of `self` that is greater than
+            /// the corresponding component of `rhs`.
+            pub fn cmpgt(&self, rhs: &$vec<T>) -> $vec<bool>
+            where T: PartialOrd
+            {
+                $vec { $($field: self.$field > rhs.$field),* }
+            }
+
+            /// Returns a mask which is `true` for each component of `self` that is less than or
+            /// equal to the corresponding component of `rhs`.
+            pub fn cmple(&self, rhs: &$vec<T>) -> $vec<bool>
+            where T: PartialOrd
+            {
+                $vec { $($field: self.$field <= rhs.$field),* }
+            }
+
+            /// Returns a mask which is `true` for each component of `self` that is less than the
+            /// corresponding component of `rhs`.
+            pub fn cmplt(&self, rhs: &$vec<T>) -> $vec<bool>
+            where T: PartialOrd
+            {
+                $vec { $($field: self.$field < rhs.$field),* }
+            }
+
+            /// Returns a mask which is `true` for each component of `self` that is not equal to
+            /// the corresponding component of `rhs`.
+            pub fn cmpne(&self, rhs: &$vec<T>) -> $vec<bool>
+            where T: PartialEq
+            {
+                $vec { $($field: self.$field != rhs.$field),* }
+            }
+
             /// Converts the vector's fields into another type.
             pub fn convert<U>(self) -> $vec<U>
             where T: Into<U>
@@ -354,6 +518,42 @@ macro_rules! impl_all {
                 $vec { $($field: self.$field.into()),* }
             }
 
+            /// Returns a reference to the component at `index`, or `None` if `index` is out of
+            /// bounds.
+            pub fn get(&self, index: usize) -> Option<&T> {
+                [$(&self.$field),*].into_iter().nth(index)
+            }
+
+            /// Returns a mutable reference to the component at `index`, or `None` if `index` is
+            /// out of bounds.
+            pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+                [$(&mut self.$field),*].into_iter().nth(index)
+            }
+
+            /// Returns the Manhattan (L1) distance between `self` and `rhs`, which is the sum of the
+            /// absolute differences between corresponding components.
+            pub fn manhattan_distance(self, rhs: $vec<T>) -> T
+            where T: Add<Output = T> + PartialOrd + Sub<Output = T>
+            {
+                $vec { $($field: diff(self.$field, rhs.$field)),* }.sum()
+            }
+
+            /// Returns the componentwise maximum of `self` and `rhs`. If a pair of components is
+            /// not comparable, the component from `self` is returned.
+            pub fn max(self, rhs: $vec<T>) -> $vec<T>
+            where T: PartialOrd
+            {
+                $vec { $($field: partial_max(self.$field, rhs.$field)),* }
+            }
+
+            /// Returns the componentwise minimum of `self` and `rhs`. If a pair of components is
+            /// not comparable, the component from `self` is returned.
+            pub fn min(self, rhs: $vec<T>) -> $vec<T>
+            where T: PartialOrd
+            {
+                $vec { $($field: partial_min(self.$field, rhs.$field)),* }
+            }
+
             /// Constructs a new vector.
             pub const fn new($($field: $t),*) -> $vec<T> {
                 $vec { $($field),* }
@@ -379,6 +579,22 @@ macro_rules! impl_all {
                 $vec { $($field: (&self.$field).into()),* }
             }
 
+            /// Converts the vector's fields into another numeric type, clamping values which are out
+            /// of range to the bounds of the target type.
+            pub fn saturating_convert<U>(self) -> $vec<U>
+            where U: SaturatingFrom<T>
+            {
+                $vec { $($field: U::saturating_from(self.$field)),* }
+            }
+
+            /// Scales the vector's offset from `anchor` by `factor`, leaving `anchor` fixed.
+            pub fn scaled_around(self, anchor: $vec<T>, factor: T) -> $vec<T>
+            where T: Add<Output = T> + Clone + Mul<Output = T> + Sub<Output = T>
+            {
+                $vec { $($field: anchor.$field.clone()
+                                 + (self.$field - anchor.$field) * factor.clone()),* }
+            }
+
             /// Gets the sum of the vector's scalar components.
             pub fn sum(self) -> T
             where T: Add<Output = T>
@@ -407,6 +623,60 @@ macro_rules! impl_all {
             {
                 Ok($vec { $($field: (&self.$field).try_into()?),* })
             }
+
+            /// Converts the vector's fields into another numeric type with the semantics of an
+            /// `as` cast.
+            pub fn wrapping_convert<U>(self) -> $vec<U>
+            where U: WrappingFrom<T>
+            {
+                $vec { $($field: U::wrapping_from(self.$field)),* }
+            }
+        }
+
+        impl $vec<bool> {
+            /// Returns `true` if every component is `true`.
+            pub fn all(self) -> bool {
+                $(self.$field)&&*
+            }
+
+            /// Returns `true` if any component is `true`.
+            pub fn any(self) -> bool {
+                $(self.$field)||*
+            }
+        }
+
+        impl<T> Select<$vec<T>> for $vec<bool> {
+            fn select(self, if_true: $vec<T>, if_false: $vec<T>) -> $vec<T> {
+                $vec {
+                    $($field: if self.$field { if_true.$field } else { if_false.$field }),*
+                }
+            }
+        }
+
+        impl<T> ComponentWise for $vec<T> {
+            type Scalar = T;
+            type Output<U> = $vec<U>;
+            const DIM: usize = $n;
+
+            fn fold<A, F>(self, init: A, mut f: F) -> A
+            where F: FnMut(A, T) -> A
+            {
+                let a = init;
+                $(let a = f(a, self.$field);)*
+                a
+            }
+
+            fn map<U, F>(self, mut f: F) -> $vec<U>
+            where F: FnMut(T) -> U
+            {
+                $vec { $($field: f(self.$field)),* }
+            }
+
+            fn zip_with<U, V, F>(self, rhs: $vec<U>, mut f: F) -> $vec<V>
+            where F: FnMut(T, U) -> V
+            {
+                $vec { $($field: f(self.$field, rhs.$field)),* }
+            }
         }
 
         impl<T> Dot for $vec<T>
@@ -453,6 +723,66 @@ macro_rules! impl_all {
             }
         }
 
+        impl<T> Index<usize> for $vec<T> {
+            type Output = T;
+
+            fn index(&self, index: usize) -> &T {
+                match self.get(index) {
+                    Some(c) => c,
+                    None => panic!("index out of bounds: the len is {} but the index is {}",
+                                   $n, index),
+                }
+            }
+        }
+
+        impl<T> IndexMut<usize> for $vec<T> {
+            fn index_mut(&mut self, index: usize) -> &mut T {
+                match self.get_mut(index) {
+                    Some(c) => c,
+                    None => panic!("index out of bounds: the len is {} but the index is {}",
+                                   $n, index),
+                }
+            }
+        }
+
+        impl<T> InnerSpace for $vec<T>
+        where $vec<T>: VectorSpace<Scalar = T>,
+              T: Add<Output = T> + Mul<Output = T>
+        {
+        }
+
+        impl<T, F> Lerp<F> for $vec<T>
+        where T: Lerp<F>,
+              F: Clone
+        {
+            fn lerp(self, rhs: $vec<T>, t: F) -> $vec<T> {
+                $vec { $($field: self.$field.lerp(rhs.$field, t.clone())),* }
+            }
+        }
+
+        impl<T> VectorN for $vec<T> {
+            type Array = [T; $n];
+
+            fn from_array(array: [T; $n]) -> $vec<T> {
+                array.into()
+            }
+
+            fn to_array(self) -> [T; $n] {
+                self.into()
+            }
+        }
+
+        impl<T> VectorSpace for $vec<T>
+        where T: Add<Output = T> + Copy + Default + Div<Output = T> + Mul<Output = T>
+                 + Sub<Output = T>
+        {
+            type Scalar = T;
+
+            fn zero() -> $vec<T> {
+                $vec::default()
+            }
+        }
+
         impl<T> From<($($t),*)> for $vec<T> {
             fn from(t: ($($t),*)) -> $vec<T> {
                 let ($($field),*) = t;
@@ -482,36 +812,371 @@ macro_rules! impl_all {
 
         impl_unary_ops! {
             impl Neg::neg for $vec($($field),*);
+            impl Not::not for $vec($($field),*);
         }
 
         impl_scalar_ops! {
+            impl BitAnd::bitand for $vec($($field),*);
+            impl BitOr::bitor for $vec($($field),*);
+            impl BitXor::bitxor for $vec($($field),*);
             impl Div::div for $vec($($field),*);
             impl Mul::mul for $vec($($field),*);
+            impl Rem::rem for $vec($($field),*);
+            impl Shl::shl for $vec($($field),*);
+            impl Shr::shr for $vec($($field),*);
         }
 
         impl_scalar_assign_ops! {
+            impl BitAndAssign::bitand_assign for $vec($($field),*);
+            impl BitOrAssign::bitor_assign for $vec($($field),*);
+            impl BitXorAssign::bitxor_assign for $vec($($field),*);
             impl DivAssign::div_assign for $vec($($field),*);
             impl MulAssign::mul_assign for $vec($($field),*);
+            impl RemAssign::rem_assign for $vec($($field),*);
+            impl ShlAssign::shl_assign for $vec($($field),*);
+            impl ShrAssign::shr_assign for $vec($($field),*);
         }
 
         impl_binary_ops! {
             impl Add::add for $vec($($field),*);
+            impl BitAnd::bitand for $vec($($field),*);
+            impl BitOr::bitor for $vec($($field),*);
+            impl BitXor::bitxor for $vec($($field),*);
             impl Div::div for $vec($($field),*);
             impl Mul::mul for $vec($($field),*);
+            impl Rem::rem for $vec($($field),*);
+            impl Shl::shl for $vec($($field),*);
+            impl Shr::shr for $vec($($field),*);
             impl Sub::sub for $vec($($field),*);
         }
 
         impl_binary_assign_ops! {
             impl AddAssign::add_assign for $vec($($field),*);
+            impl BitAndAssign::bitand_assign for $vec($($field),*);
+            impl BitOrAssign::bitor_assign for $vec($($field),*);
+            impl BitXorAssign::bitxor_assign for $vec($($field),*);
             impl DivAssign::div_assign for $vec($($field),*);
             impl MulAssign::mul_assign for $vec($($field),*);
+            impl RemAssign::rem_assign for $vec($($field),*);
+            impl ShlAssign::shl_assign for $vec($($field),*);
+            impl ShrAssign::shr_assign for $vec($($field),*);
             impl SubAssign::sub_assign for $vec($($field),*);
         }
     )* };
 }
 
+/// Implements functions relating vector components to axes.
+macro_rules! impl_axis {
+    {
+        $(impl $vec:ident: $axis:ident($($field:ident: $variant:ident => $flip:ident),*);)*
+    } => { $(
+        impl<T> $vec<T> {
+            $(
+                #[doc = concat!("Returns `self` with the `", stringify!($field),
+                                "` component negated.")]
+                pub fn $flip(mut self) -> $vec<T>
+                where T: Neg<Output = T>
+                {
+                    self.$field = -self.$field;
+                    self
+                }
+            )*
+
+            /// Returns the axis of the greatest component. If several components are equally
+            /// great, the first such axis is returned.
+            pub fn max_axis(&self) -> $axis
+            where T: PartialOrd
+            {
+                let mut a: Option<(&T, $axis)> = None;
+                $(a = match a {
+                    None => Some((&self.$field, $axis::$variant)),
+                    Some((max, _)) if self.$field > *max => Some((&self.$field, $axis::$variant)),
+                    _ => a,
+                };)*
+                a.unwrap().1
+            }
+
+            /// Returns the greatest component. If some components are not comparable, earlier
+            /// components take precedence.
+            pub fn max_element(self) -> T
+            where T: PartialOrd
+            {
+                let mut a = None;
+                $(a = match a {
+                    None => Some(self.$field),
+                    Some(a) => Some(partial_max(a, self.$field)),
+                };)*
+                a.unwrap()
+            }
+
+            /// Returns the axis of the least component. If several components are equally
+            /// small, the first such axis is returned.
+            pub fn min_axis(&self) -> $axis
+            where T: PartialOrd
+            {
+                let mut a: Option<(&T, $axis)> = None;
+                $(a = match a {
+                    None => Some((&self.$field, $axis::$variant)),
+                    Some((min, _)) if self.$field < *min => Some((&self.$field, $axis::$variant)),
+                    _ => a,
+                };)*
+                a.unwrap().1
+            }
+
+            /// Returns the least component. If some components are not comparable, earlier
+            /// components take precedence.
+            pub fn min_element(self) -> T
+            where T: PartialOrd
+            {
+                let mut a = None;
+                $(a = match a {
+                    None => Some(self.$field),
+                    Some(a) => Some(partial_min(a, self.$field)),
+                };)*
+                a.unwrap()
+            }
+
+            /// Returns `self` reflected across the plane perpendicular to `axis`, negating the
+            /// corresponding component.
+            pub fn reflect_axis(self, axis: $axis) -> $vec<T>
+            where T: Neg<Output = T>
+            {
+                match axis {
+                    $($axis::$variant => self.$flip(),)*
+                }
+            }
+        }
+    )* };
+}
+
 impl_all! {
     impl Vector2(x: T, y: T; 2);
     impl Vector3(x: T, y: T, z: T; 3);
     impl Vector4(x: T, y: T, z: T, w: T; 4);
 }
+
+impl_axis! {
+    impl Vector2: Axis2(x: X => flip_x, y: Y => flip_y);
+    impl Vector3: Axis3(x: X => flip_x, y: Y => flip_y, z: Z => flip_z);
+    impl Vector4: Axis4(x: X => flip_x, y: Y => flip_y, z: Z => flip_z, w: W => flip_w);
+}
+
+/// Implements named direction constants for vectors with signed components. The constants follow
+/// a Y-up, right-handed convention, in which forward is `-Z` as in OpenGL.
+macro_rules! impl_directions {
+    { $($t:ident: $zero:literal, $one:literal;)* } => { $(
+        impl Vector2<$t> {
+            /// Unit vector pointing down, `(0, -1)`.
+            pub const DOWN: Vector2<$t> = Vector2 { x: $zero, y: -$one };
+            /// Unit vector pointing left, `(-1, 0)`.
+            pub const LEFT: Vector2<$t> = Vector2 { x: -$one, y: $zero };
+            /// Unit vector pointing right, `(1, 0)`.
+            pub const RIGHT: Vector2<$t> = Vector2 { x: $one, y: $zero };
+            /// Unit vector pointing up, `(0, 1)`.
+            pub const UP: Vector2<$t> = Vector2 { x: $zero, y: $one };
+        }
+
+        impl Vector3<$t> {
+            /// Unit vector pointing backward, `(0, 0, 1)`.
+            pub const BACK: Vector3<$t> = Vector3 { x: $zero, y: $zero, z: $one };
+            /// Unit vector pointing down, `(0, -1, 0)`.
+            pub const DOWN: Vector3<$t> = Vector3 { x: $zero, y: -$one, z: $zero };
+            /// Unit vector pointing forward, `(0, 0, -1)`.
+            pub const FORWARD: Vector3<$t> = Vector3 { x: $zero, y: $zero, z: -$one };
+            /// Unit vector pointing left, `(-1, 0, 0)`.
+            pub const LEFT: Vector3<$t> = Vector3 { x: -$one, y: $zero, z: $zero };
+            /// Unit vector pointing right, `(1, 0, 0)`.
+            pub const RIGHT: Vector3<$t> = Vector3 { x: $one, y: $zero, z: $zero };
+            /// Unit vector pointing up, `(0, 1, 0)`.
+            pub const UP: Vector3<$t> = Vector3 { x: $zero, y: $one, z: $zero };
+        }
+    )* };
+}
+
+impl_directions! {
+    i8: 0, 1;
+    i16: 0, 1;
+    i32: 0, 1;
+    i64: 0, 1;
+    i128: 0, 1;
+    isize: 0, 1;
+    f32: 0.0, 1.0;
+    f64: 0.0, 1.0;
+}
+
+//--------------------------------------------------------------------------------------------------
+
+/// Returns the absolute difference between `a` and `b` without requiring a signed type.
+fn diff<T: PartialOrd + Sub<Output = T>>(a: T, b: T) -> T {
+    if a < b {
+        b - a
+    } else {
+        a - b
+    }
+}
+
+fn partial_max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+fn partial_min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_axes() {
+    assert_eq!(vec3(3, -1, 7).max_axis(), Axis3::Z);
+    assert_eq!(vec3(3, -1, 7).min_axis(), Axis3::Y);
+    assert_eq!(vec3(3, 3, 3).max_axis(), Axis3::X);
+    assert_eq!(vec4(3, -1, 7, 2).max_element(), 7);
+    assert_eq!(vec4(3, -1, 7, 2).min_element(), -1);
+    assert_eq!(vec3(3, -1, 7).flip_y(), vec3(3, 1, 7));
+    assert_eq!(vec4(3, -1, 7, 2).reflect_axis(Axis4::W), vec4(3, -1, 7, -2));
+}
+
+#[test]
+fn test_vec_bit_ops() {
+    assert_eq!(vec2(0b1100u8, 0b1010) & vec2(0b1010, 0b0110), vec2(0b1000, 0b0010));
+    assert_eq!(vec2(1u32, 3) << 4, vec2(16, 48));
+    assert_eq!(!vec2(0u8, 255), vec2(255, 0));
+
+    let mut v = vec3(-16i32, 17, 1);
+    v >>= vec3(2, 1, 0);
+    assert_eq!(v, vec3(-4, 8, 1));
+}
+
+#[test]
+fn test_vec2_quarter_turns() {
+    assert_eq!(vec2(3, 1).rotate_90_ccw(), vec2(-1, 3));
+    assert_eq!(vec2(3, 1).rotate_90_cw(), vec2(1, -3));
+    assert_eq!(vec2(3, 1).rotate_180(), vec2(-3, -1));
+    assert_eq!(vec2(3, 1).rotate_90_ccw().rotate_90_ccw(), vec2(3, 1).rotate_180());
+}
+
+#[test]
+fn test_vec_triple() {
+    assert_eq!(vec3(1, 0, 0).triple(vec3(0, 1, 0), vec3(0, 0, 1)), 1);
+    assert_eq!(vec3(0, 1, 0).triple(vec3(1, 0, 0), vec3(0, 0, 1)), -1);
+    assert_eq!(crate::triple(vec3(2, 0, 0), vec3(0, 3, 0), vec3(1, 1, 4)), 24);
+}
+
+#[test]
+fn test_vec_distances() {
+    assert_eq!(vec2(1u32, 8).manhattan_distance(vec2(4, 2)), 9);
+    assert_eq!(vec2(1u32, 8).chebyshev_distance(vec2(4, 2)), 6);
+    assert_eq!(vec3(-1i32, 2, -3).manhattan_length(), 6u32);
+    assert_eq!(vec3(-1i32, 2, -3).chebyshev_length(), 3u32);
+}
+
+#[test]
+fn test_vec3_conventions() {
+    assert_eq!(vec3(1, 2, 3).yup_to_zup(), vec3(1, -3, 2));
+    assert_eq!(vec3(1, 2, 3).yup_to_zup().zup_to_yup(), vec3(1, 2, 3));
+    assert_eq!(vec3(1, 2, 3).flip_handedness(), vec3(1, 2, -3));
+}
+
+#[test]
+fn test_vec_directions() {
+    assert_eq!(Vector2::<i32>::UP + Vector2::<i32>::DOWN, vec2(0, 0));
+    assert_eq!(Vector3::<f32>::RIGHT.cross(Vector3::<f32>::UP), Vector3::<f32>::BACK);
+    assert_eq!(Vector3::<i8>::UP.cross(Vector3::<i8>::FORWARD), Vector3::<i8>::LEFT);
+}
+
+#[test]
+fn test_vec_select() {
+    let v = vec3(-0.05f32, 0.5, -0.8);
+    let dead_zone = v.abs().cmplt(&vec3(0.1, 0.1, 0.1));
+    assert_eq!(dead_zone, vec3(true, false, false));
+    assert_eq!(crate::select(dead_zone, vec3(0.0, 0.0, 0.0), v), vec3(0.0, 0.5, -0.8));
+    assert!(vec2(1, 2).cmple(&vec2(1, 3)).all());
+    assert!(!vec2(1, 2).cmpeq(&vec2(0, 3)).any());
+}
+
+#[test]
+fn test_vec_lerp() {
+    assert_eq!(vec2(0.0f32, 10.0).lerp(vec2(10.0, 20.0), 0.25), vec2(2.5, 12.5));
+    assert_eq!(crate::lerp(vec3(1.0f64, 2.0, 3.0), vec3(4.0, 5.0, 6.0), 1.0), vec3(4.0, 5.0, 6.0));
+}
+
+#[test]
+fn test_vec_component_wise() {
+    fn clamp_each<V: ComponentWise<Scalar = i32>>(v: V, lo: i32, hi: i32) -> V::Output<i32> {
+        v.map(|c| c.clamp(lo, hi))
+    }
+
+    assert_eq!(clamp_each(vec3(-5, 3, 12), 0, 10), vec3(0, 3, 10));
+    assert_eq!(vec2(1, 2).zip_with(vec2(0.5, 0.25), |a, b| a as f32 * b), vec2(0.5, 0.5));
+    assert_eq!(vec4(1, 2, 3, 4).fold(0, |a, c| a * 10 + c), 1234);
+    assert_eq!(<Vector4<u8> as ComponentWise>::DIM, 4);
+}
+
+#[test]
+fn test_vec_index() {
+    fn widest_axis<V: VectorN<Scalar = i32>>(v: V) -> usize {
+        (0..V::DIM).max_by_key(|&i| v[i].abs()).unwrap()
+    }
+
+    let mut v = vec3(4, -9, 2);
+    assert_eq!(widest_axis(v), 1);
+    v[2] = 7;
+    assert_eq!(v.to_array(), [4, -9, 7]);
+    assert_eq!(Vector4::from_array([1, 2, 3, 4]).get(3), Some(&4));
+    assert_eq!(v.get(3), None);
+}
+
+#[test]
+fn test_vec_convert() {
+    assert_eq!(vec2(300, -3).saturating_convert::<u8>(), vec2(255, 0));
+    assert_eq!(vec2(300, -3).wrapping_convert::<u8>(), vec2(44, 253));
+    let v = vec3(1e300f64, f64::NAN, 0.5).saturating_convert::<f32>();
+    assert_eq!((v.x, v.z), (f32::MAX, 0.5));
+    assert!(v.y.is_nan());
+    assert_eq!(vec2(-1.5f32, f32::NAN).saturating_convert::<i8>(), vec2(-1, 0));
+    assert_eq!(vec2(1e300f64, 0.5).wrapping_convert::<f32>(), vec2(f32::INFINITY, 0.5));
+}
+
+#[test]
+fn test_vec_clone_only_scalar() {
+    // Stands in for an arbitrary-precision integer, which is `Clone` but not `Copy`.
+    #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+    struct Big(i64);
+
+    impl Add for Big {
+        type Output = Big;
+
+        fn add(self, rhs: Big) -> Big {
+            Big(self.0 + rhs.0)
+        }
+    }
+
+    impl Mul for Big {
+        type Output = Big;
+
+        fn mul(self, rhs: Big) -> Big {
+            Big(self.0 * rhs.0)
+        }
+    }
+
+    impl Sub for Big {
+        type Output = Big;
+
+        fn sub(self, rhs: Big) -> Big {
+            Big(self.0 - rhs.0)
+        }
+    }
+
+    let big = |x, y, z| vec3(Big(x), Big(y), Big(z));
+    assert_eq!(big(1, 2, 3) * Big(2), big(2, 4, 6));
+    assert_eq!(big(1, 0, 0).cross(big(0, 1, 0)), big(0, 0, 1));
+    assert_eq!(crate::triple(big(0, 0, 2), big(1, 0, 0), big(0, 3, 0)), Big(6));
+}
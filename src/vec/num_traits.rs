@@ -7,15 +7,18 @@
  */
 
 use num_traits::{
+    Bounded,
     CheckedAdd,
     CheckedDiv,
     CheckedMul,
     CheckedNeg,
     CheckedSub,
+    NumCast,
     One,
     SaturatingAdd,
     SaturatingMul,
     SaturatingSub,
+    ToPrimitive,
     WrappingAdd,
     WrappingMul,
     WrappingNeg,
@@ -88,6 +91,29 @@ macro_rules! impl_checked_binary_ops {
 /// Implements all relevant `num-traits` traits for vector types.
 macro_rules! impl_all {
     { $(impl $vec:ident($($field:ident),*);)* } => { $(
+        impl<T> $vec<T> {
+            /// Converts the vector's fields into another numeric type using [NumCast]. Returns
+            /// `None` if any field cannot be represented by the target type.
+            pub fn cast<U>(self) -> Option<$vec<U>>
+            where T: ToPrimitive,
+                  U: NumCast
+            {
+                Some($vec { $($field: U::from(self.$field)?),* })
+            }
+        }
+
+        impl<T> Bounded for $vec<T>
+        where T: Bounded
+        {
+            fn max_value() -> $vec<T> {
+                $vec { $($field: T::max_value()),* }
+            }
+
+            fn min_value() -> $vec<T> {
+                $vec { $($field: T::min_value()),* }
+            }
+        }
+
         impl<T> One for $vec<T>
         where T: One + PartialEq
         {